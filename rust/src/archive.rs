@@ -0,0 +1,116 @@
+// The ranged-read helpers are only used by `FilesClient`.
+#![cfg_attr(not(feature = "http"), allow(dead_code))]
+
+use crate::error::{HubApiError, Result};
+
+// ---------------------------------------------------------------------------
+// Zip central-directory parsing
+// ---------------------------------------------------------------------------
+
+const EOCD_SIGNATURE: u32 = 0x0605_4b50;
+const EOCD_LEN: usize = 22;
+const CENTRAL_SIGNATURE: u32 = 0x0201_4b50;
+const CENTRAL_HEADER_LEN: usize = 46;
+
+/// How many trailing bytes of an archive are enough to contain the
+/// end-of-central-directory record (fixed part plus the longest comment).
+pub(crate) const TAIL_LEN: u64 = EOCD_LEN as u64 + u16::MAX as u64;
+
+/// One entry of a zip archive, as listed in its central directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry {
+    pub name: String,
+    pub compressed_size: u64,
+    pub size: u64,
+}
+
+impl ArchiveEntry {
+    /// Directory entries are stored with a trailing `/`.
+    pub fn is_dir(&self) -> bool {
+        self.name.ends_with('/')
+    }
+}
+
+/// Location of the central directory, read from the end-of-central-directory
+/// record.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CentralDirectory {
+    pub entries: u16,
+    pub size: u64,
+    pub offset: u64,
+}
+
+fn invalid(reason: &str) -> HubApiError {
+    HubApiError::Api {
+        status: 0,
+        message: format!("Invalid zip archive: {reason}"),
+        response: None,
+    }
+}
+
+fn u16_at(buf: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([buf[at], buf[at + 1]])
+}
+
+fn u32_at(buf: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([buf[at], buf[at + 1], buf[at + 2], buf[at + 3]])
+}
+
+/// Find the end-of-central-directory record in the last bytes of an archive.
+pub(crate) fn find_central_directory(tail: &[u8]) -> Result<CentralDirectory> {
+    if tail.len() < EOCD_LEN {
+        return Err(invalid("too short"));
+    }
+    let eocd = (0..=tail.len() - EOCD_LEN)
+        .rev()
+        .find(|&at| u32_at(tail, at) == EOCD_SIGNATURE)
+        .ok_or_else(|| invalid("end of central directory not found"))?;
+
+    let entries = u16_at(tail, eocd + 10);
+    let size = u32_at(tail, eocd + 12);
+    let offset = u32_at(tail, eocd + 16);
+    if entries == u16::MAX || size == u32::MAX || offset == u32::MAX {
+        return Err(invalid("zip64 archives are not supported"));
+    }
+    Ok(CentralDirectory {
+        entries,
+        size: size.into(),
+        offset: offset.into(),
+    })
+}
+
+/// Parse `entries` central-directory headers from `buf`.
+pub(crate) fn parse_entries(buf: &[u8], entries: u16) -> Result<Vec<ArchiveEntry>> {
+    let mut out = Vec::with_capacity(entries.into());
+    let mut at = 0;
+    for _ in 0..entries {
+        if buf.len() < at + CENTRAL_HEADER_LEN || u32_at(buf, at) != CENTRAL_SIGNATURE {
+            return Err(invalid("truncated central directory"));
+        }
+        let name_len = usize::from(u16_at(buf, at + 28));
+        let extra_len = usize::from(u16_at(buf, at + 30));
+        let comment_len = usize::from(u16_at(buf, at + 32));
+        let name_start = at + CENTRAL_HEADER_LEN;
+        let name = buf
+            .get(name_start..name_start + name_len)
+            .ok_or_else(|| invalid("truncated central directory"))?;
+
+        out.push(ArchiveEntry {
+            name: String::from_utf8_lossy(name).into_owned(),
+            compressed_size: u32_at(buf, at + 20).into(),
+            size: u32_at(buf, at + 24).into(),
+        });
+        at = name_start + name_len + extra_len + comment_len;
+    }
+    Ok(out)
+}
+
+/// List the entries of a zip archive held fully in memory.
+pub fn list_entries(archive: &[u8]) -> Result<Vec<ArchiveEntry>> {
+    let cd = find_central_directory(archive)?;
+    let start = usize::try_from(cd.offset).map_err(|_| invalid("bad offset"))?;
+    let dir = archive
+        .get(start..)
+        .ok_or_else(|| invalid("central directory out of bounds"))?;
+    parse_entries(dir, cd.entries)
+}
@@ -0,0 +1,507 @@
+//! Asynchronous client, available with the `async` feature.
+//!
+//! [`AsyncHubClient`] mirrors the endpoint methods of the blocking
+//! [`HubClient`](crate::HubClient) and returns the same models, so most code
+//! can move between the two by adding `.await`.  The exception is uploads:
+//! `versions().create` and `versions().update` take in-memory
+//! `(filename, bytes)` tuples rather than
+//! [`FileSource`](crate::FileSource)s.  It needs to run inside a Tokio
+//! runtime.
+//!
+//! ```no_run
+//! # async fn run() -> hub01_client::error::Result<()> {
+//! use hub01_client::{AsyncHubClient, ListProjectsParams};
+//!
+//! let client = AsyncHubClient::new("https://hub01-shop.srgnis.com/api", None)?;
+//! let projects = client.projects().list(&ListProjectsParams::default()).await?;
+//! for project in &projects.data {
+//!     println!("{}", project.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Not available here:
+//!
+//! - [`HubClient::builder`](crate::HubClient::builder) and the opt-in extras
+//!   (retries, rate-limit waiting, circuit breaker, request coalescing,
+//!   conditional requests, disk cache, checksum verification, slug
+//!   suggestions, read-only mode, audit hook, metrics);
+//! - entity handles (`project()`) and `files()` (downloads, ranged reads,
+//!   archive listings);
+//! - helpers built on several requests: `list_all`, `latest_matching`,
+//!   `capabilities`, `validate_dependencies` and the tag resolver.
+
+use reqwest::header::{HeaderValue, AUTHORIZATION};
+use reqwest::multipart;
+use reqwest::{Client, RequestBuilder};
+use serde::de::DeserializeOwned;
+
+use crate::client::{
+    default_headers, parse_response, parse_retry_after, same_origin, tag_list_query, DataWrapper,
+};
+use crate::error::{ErrorResponse, HubApiError, Result};
+use crate::models::*;
+use crate::params::{
+    CreateVersionParams, ListProjectsParams, ListVersionsParams, ProjectOrderBy, SortDirection,
+    UpdateVersionParams, VersionOrderBy, MAX_PER_PAGE, PER_PAGE_OPTIONS,
+};
+use crate::slug::{ProjectSlug, TagSlug, Username, VersionSlug};
+use crate::versions;
+
+/// Decode a JSON response body into `T`.
+fn decode<T: DeserializeOwned>(data: Option<serde_json::Value>) -> Result<T> {
+    serde_json::from_value(data.unwrap_or_default()).map_err(|e| HubApiError::Api {
+        status: 0,
+        message: format!("Deserialization error: {e}"),
+        response: None,
+    })
+}
+
+/// Decode a `{ "data": ... }` wrapped response body into `T`.
+fn decode_data<T: DeserializeOwned>(data: Option<serde_json::Value>) -> Result<T> {
+    decode::<DataWrapper<T>>(data).map(|wrapper| wrapper.data)
+}
+
+struct AsyncBaseClient {
+    base_url: String,
+    http: Client,
+    /// Sent per request rather than as a default header, so that it only
+    /// goes to the API's own origin.
+    authorization: Option<HeaderValue>,
+}
+
+impl AsyncBaseClient {
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}{endpoint}", self.base_url)
+    }
+
+    async fn send(&self, request: RequestBuilder) -> Result<Option<serde_json::Value>> {
+        let mut request = request.build()?;
+        if let Some(ref authorization) = self.authorization {
+            if same_origin(&self.base_url, request.url().as_str()) {
+                request
+                    .headers_mut()
+                    .insert(AUTHORIZATION, authorization.clone());
+            }
+        }
+        let (method, url) = (request.method().to_string(), request.url().to_string());
+        let response = self.http.execute(request).await?;
+        let status = response.status().as_u16();
+        let headers: Vec<(String, String)> = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let retry_after = parse_retry_after(
+            response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok()),
+        );
+        let body = if status == 204 {
+            Default::default()
+        } else {
+            response.bytes().await.unwrap_or_default()
+        };
+        parse_response(status, retry_after, &body).map_err(|e| {
+            e.with_response(ErrorResponse::new(&method, &url, status, &headers, &body))
+        })
+    }
+
+    async fn get(
+        &self,
+        endpoint: &str,
+        query: &[(String, String)],
+    ) -> Result<Option<serde_json::Value>> {
+        self.send(self.http.get(self.url(endpoint)).query(query))
+            .await
+    }
+
+    async fn post_multipart(
+        &self,
+        endpoint: &str,
+        fields: Vec<(String, String)>,
+        files: &[(&str, Vec<u8>)],
+    ) -> Result<Option<serde_json::Value>> {
+        let mut form = multipart::Form::new();
+        for (name, value) in fields {
+            form = form.text(name, value);
+        }
+        for (filename, bytes) in files {
+            let part = multipart::Part::bytes(bytes.clone())
+                .file_name(filename.to_string())
+                .mime_str("application/octet-stream")
+                .map_err(|e| HubApiError::Api {
+                    status: 0,
+                    message: format!("Invalid MIME type: {e}"),
+                    response: None,
+                })?;
+            form = form.part("files[]", part);
+        }
+        self.send(self.http.post(self.url(endpoint)).multipart(form))
+            .await
+    }
+
+    async fn delete(&self, endpoint: &str) -> Result<Option<serde_json::Value>> {
+        self.send(self.http.delete(self.url(endpoint))).await
+    }
+}
+
+// ===========================================================================
+// Public async client
+// ===========================================================================
+
+/// Asynchronous counterpart of [`HubClient`](crate::HubClient).
+pub struct AsyncHubClient {
+    base: AsyncBaseClient,
+}
+
+impl AsyncHubClient {
+    /// Create a new client.  Pass a token for authenticated operations.
+    pub fn new(base_url: &str, token: Option<&str>) -> Result<Self> {
+        let mut headers = default_headers(token)?;
+        let authorization = headers.remove(AUTHORIZATION);
+        let http = Client::builder().default_headers(headers).build()?;
+        Ok(Self {
+            base: AsyncBaseClient {
+                base_url: base_url.trim_end_matches('/').to_string(),
+                http,
+                authorization,
+            },
+        })
+    }
+
+    /// Validate the configured API token.
+    pub async fn test_token(&self) -> Result<serde_json::Value> {
+        self.base
+            .get("/test-token", &[])
+            .await?
+            .ok_or_else(|| HubApiError::Api {
+                status: 0,
+                message: "Empty response from test-token".into(),
+                response: None,
+            })
+    }
+
+    /// Fetch the page after `page` by following `links.next`.
+    ///
+    /// Returns `Ok(None)` on the last page.
+    pub async fn next_page<T: DeserializeOwned>(
+        &self,
+        page: &PaginatedResponse<T>,
+    ) -> Result<Option<PaginatedResponse<T>>> {
+        match page.next_url() {
+            Some(url) => decode(self.base.send(self.base.http.get(url)).await?).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetch the page before `page` by following `links.prev`.
+    ///
+    /// Returns `Ok(None)` on the first page.
+    pub async fn prev_page<T: DeserializeOwned>(
+        &self,
+        page: &PaginatedResponse<T>,
+    ) -> Result<Option<PaginatedResponse<T>>> {
+        match page.prev_url() {
+            Some(url) => decode(self.base.send(self.base.http.get(url)).await?).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    // -- sub-client accessors ------------------------------------------------
+
+    pub fn project_types(&self) -> ProjectTypesClient<'_> {
+        ProjectTypesClient { client: self }
+    }
+
+    pub fn projects(&self) -> ProjectsClient<'_> {
+        ProjectsClient { client: self }
+    }
+
+    pub fn versions(&self) -> ProjectVersionsClient<'_> {
+        ProjectVersionsClient { client: self }
+    }
+
+    pub fn tags(&self) -> TagsClient<'_> {
+        TagsClient { client: self }
+    }
+
+    pub fn users(&self) -> UsersClient<'_> {
+        UsersClient { client: self }
+    }
+}
+
+// ===========================================================================
+// Sub-clients
+// ===========================================================================
+
+// ---- Project Types --------------------------------------------------------
+
+pub struct ProjectTypesClient<'a> {
+    client: &'a AsyncHubClient,
+}
+
+impl ProjectTypesClient<'_> {
+    /// List all project types.
+    pub async fn list(&self) -> Result<Vec<ProjectType>> {
+        decode_data(self.client.base.get("/v1/project_types", &[]).await?)
+    }
+
+    /// Get a single project type by slug.
+    pub async fn get(&self, slug: &str) -> Result<ProjectType> {
+        let endpoint = format!("/v1/project_type/{slug}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+}
+
+// ---- Projects -------------------------------------------------------------
+
+pub struct ProjectsClient<'a> {
+    client: &'a AsyncHubClient,
+}
+
+impl ProjectsClient<'_> {
+    /// List / search projects with pagination.
+    pub async fn list(&self, params: &ListProjectsParams) -> Result<PaginatedResponse<Project>> {
+        params.validate()?;
+        decode(
+            self.client
+                .base
+                .get("/v1/projects", &params.to_query())
+                .await?,
+        )
+    }
+
+    /// Return only the total number of projects matching `params`.
+    ///
+    /// See [`crate::ProjectsClient::count`].
+    pub async fn count(&self, params: &ListProjectsParams) -> Result<u64> {
+        let resp = self
+            .list(&ListProjectsParams {
+                per_page: PER_PAGE_OPTIONS[0],
+                page: 1,
+                ..params.clone()
+            })
+            .await?;
+        resp.meta
+            .as_ref()
+            .and_then(|m| m.get("total"))
+            .and_then(|t| t.as_u64())
+            .ok_or_else(|| HubApiError::Api {
+                status: 0,
+                message: "Missing meta.total in projects response".into(),
+                response: None,
+            })
+    }
+
+    /// Enumerate every project matching `params` that was created at or
+    /// before `cutoff`, in a stable order.
+    ///
+    /// See [`crate::ProjectsClient::crawl_snapshot`].
+    pub async fn crawl_snapshot(
+        &self,
+        params: &ListProjectsParams,
+        cutoff: &str,
+    ) -> Result<Vec<Project>> {
+        let mut page = self
+            .list(&ListProjectsParams {
+                order_by: ProjectOrderBy::CreatedAt,
+                order_direction: SortDirection::Asc,
+                per_page: MAX_PER_PAGE,
+                page: 1,
+                ..params.clone()
+            })
+            .await?;
+        let mut projects = Vec::new();
+
+        loop {
+            for project in page.data.drain(..) {
+                if project.created_at.as_str() > cutoff {
+                    return Ok(projects);
+                }
+                projects.push(project);
+            }
+            page = match self.client.next_page(&page).await? {
+                Some(next) => next,
+                None => return Ok(projects),
+            };
+        }
+    }
+
+    /// Get a single project by slug.
+    pub async fn get(&self, slug: &ProjectSlug) -> Result<Project> {
+        let endpoint = format!("/v1/project/{slug}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+}
+
+// ---- Project Versions -----------------------------------------------------
+
+pub struct ProjectVersionsClient<'a> {
+    client: &'a AsyncHubClient,
+}
+
+impl ProjectVersionsClient<'_> {
+    /// List all versions of a project.
+    pub async fn list(
+        &self,
+        slug: &ProjectSlug,
+        params: &ListVersionsParams,
+    ) -> Result<PaginatedResponse<ProjectVersion>> {
+        params.validate()?;
+        let endpoint = format!("/v1/project/{slug}/versions");
+        decode(self.client.base.get(&endpoint, &params.to_query()).await?)
+    }
+
+    /// Resolve a channel to the newest matching version, by release date.
+    ///
+    /// See [`crate::ProjectVersionsClient::resolve`].
+    pub async fn resolve(
+        &self,
+        slug: &ProjectSlug,
+        channel: VersionChannel,
+    ) -> Result<ProjectVersion> {
+        let mut page = self
+            .list(
+                slug,
+                &ListVersionsParams {
+                    order_by: VersionOrderBy::ReleaseDate,
+                    order_direction: SortDirection::Desc,
+                    per_page: MAX_PER_PAGE,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        loop {
+            if let Some(pos) = versions::newest_released(&page.data, channel) {
+                return Ok(page.data.swap_remove(pos));
+            }
+            match self.client.next_page(&page).await? {
+                Some(next) => page = next,
+                None => {
+                    return Err(HubApiError::NotFound {
+                        message: format!("Project '{slug}' has no {channel} version"),
+                        suggestions: Vec::new(),
+                        response: None,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Get a single project version.
+    pub async fn get(&self, slug: &ProjectSlug, version: &VersionSlug) -> Result<ProjectVersion> {
+        let endpoint = format!("/v1/project/{slug}/version/{version}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+
+    /// Create a new project version with file uploads.
+    ///
+    /// `files` is a list of `(filename, bytes)` tuples.
+    pub async fn create(
+        &self,
+        slug: &ProjectSlug,
+        params: &CreateVersionParams,
+        files: &[(&str, Vec<u8>)],
+    ) -> Result<ProjectVersion> {
+        let endpoint = format!("/v1/project/{slug}/versions");
+        decode_data(
+            self.client
+                .base
+                .post_multipart(&endpoint, params.to_fields(), files)
+                .await?,
+        )
+    }
+
+    /// Update an existing project version.
+    ///
+    /// `files` is an optional list of `(filename, bytes)` tuples to upload.
+    pub async fn update(
+        &self,
+        slug: &ProjectSlug,
+        version: &VersionSlug,
+        params: &UpdateVersionParams,
+        files: Option<&[(&str, Vec<u8>)]>,
+    ) -> Result<ProjectVersion> {
+        let endpoint = format!("/v1/project/{slug}/version/{version}");
+        decode_data(
+            self.client
+                .base
+                .post_multipart(
+                    &endpoint,
+                    params.to_fields(version),
+                    files.unwrap_or_default(),
+                )
+                .await?,
+        )
+    }
+
+    /// Delete a project version.
+    pub async fn delete(&self, slug: &ProjectSlug, version: &VersionSlug) -> Result<()> {
+        let endpoint = format!("/v1/project/{slug}/version/{version}");
+        self.client.base.delete(&endpoint).await?;
+        Ok(())
+    }
+}
+
+// ---- Tags -----------------------------------------------------------------
+
+pub struct TagsClient<'a> {
+    client: &'a AsyncHubClient,
+}
+
+impl TagsClient<'_> {
+    /// List all project tags.
+    pub async fn list_project_tags(
+        &self,
+        plain: bool,
+        project_type: Option<&str>,
+    ) -> Result<Vec<ProjectTag>> {
+        let query = tag_list_query(plain, project_type);
+        decode_data(self.client.base.get("/v1/project_tags", &query).await?)
+    }
+
+    /// Get a single project tag by slug.
+    pub async fn get_project_tag(&self, slug: &TagSlug) -> Result<ProjectTag> {
+        let endpoint = format!("/v1/project_tag/{slug}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+
+    /// List all version tags.
+    pub async fn list_version_tags(
+        &self,
+        plain: bool,
+        project_type: Option<&str>,
+    ) -> Result<Vec<ProjectVersionTag>> {
+        let query = tag_list_query(plain, project_type);
+        decode_data(self.client.base.get("/v1/version_tags", &query).await?)
+    }
+
+    /// Get a single version tag by slug.
+    pub async fn get_version_tag(&self, slug: &TagSlug) -> Result<ProjectVersionTag> {
+        let endpoint = format!("/v1/version_tag/{slug}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+}
+
+// ---- Users ----------------------------------------------------------------
+
+pub struct UsersClient<'a> {
+    client: &'a AsyncHubClient,
+}
+
+impl UsersClient<'_> {
+    /// Get a user profile by username.
+    pub async fn get(&self, name: &Username) -> Result<User> {
+        let endpoint = format!("/v1/user/{name}");
+        decode_data(self.client.base.get(&endpoint, &[]).await?)
+    }
+
+    /// Get projects owned by a user.
+    pub async fn get_projects(&self, name: &Username) -> Result<PaginatedResponse<Project>> {
+        let endpoint = format!("/v1/user/{name}/projects");
+        decode(self.client.base.get(&endpoint, &[]).await?)
+    }
+}
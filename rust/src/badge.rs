@@ -0,0 +1,107 @@
+//! SVG and Markdown badges for embedding project stats in READMEs.
+//!
+//! ```
+//! use hub01_client::badge;
+//!
+//! let svg = badge::svg("downloads", &badge::compact_count(12_345), badge::BLUE);
+//! assert!(svg.starts_with("<svg"));
+//! assert!(svg.contains("12.3k"));
+//!
+//! let md = badge::markdown(
+//!     "Downloads",
+//!     "https://example.com/badges/my-mod.svg",
+//!     "https://hub01-shop.srgnis.com/projects/mod/my-mod",
+//! );
+//! assert_eq!(
+//!     md,
+//!     "[![Downloads](https://example.com/badges/my-mod.svg)](https://hub01-shop.srgnis.com/projects/mod/my-mod)"
+//! );
+//! ```
+
+use crate::models::{Project, ProjectVersion};
+
+pub const BLUE: &str = "#007ec6";
+pub const GREEN: &str = "#4c1";
+pub const ORANGE: &str = "#fe7d37";
+const LABEL_COLOR: &str = "#555";
+
+/// Approximate width in pixels of `text` in 11px Verdana, the font used by
+/// the badge.  Exact metrics would need the font; this keeps text from
+/// overflowing for common labels.
+fn text_width(text: &str) -> u32 {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'l' | 'j' | '.' | ',' | ':' | '\'' | '|' | '!' => 4,
+            'f' | 't' | 'r' | ' ' | '-' | '(' | ')' => 5,
+            'm' | 'w' | 'M' | 'W' => 11,
+            c if c.is_ascii_uppercase() => 8,
+            _ => 7,
+        })
+        .sum()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Render a flat two-part badge: `label` on grey, `message` on `color`.
+pub fn svg(label: &str, message: &str, color: &str) -> String {
+    let left = text_width(label) + 10;
+    let right = text_width(message) + 10;
+    let width = left + right;
+    let (label, message, color) = (escape(label), escape(message), escape(color));
+    format!(
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="20" role="img" aria-label="{label}: {message}">"#,
+            r#"<title>{label}: {message}</title>"#,
+            r#"<rect width="{left}" height="20" fill="{label_color}"/>"#,
+            r#"<rect x="{left}" width="{right}" height="20" fill="{color}"/>"#,
+            r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">"##,
+            r#"<text x="{label_x}" y="14">{label}</text>"#,
+            r#"<text x="{message_x}" y="14">{message}</text>"#,
+            r#"</g></svg>"#,
+        ),
+        width = width,
+        left = left,
+        right = right,
+        label = label,
+        message = message,
+        color = color,
+        label_color = LABEL_COLOR,
+        label_x = left / 2,
+        message_x = left + right / 2,
+    )
+}
+
+/// Format a count the way badges usually show it: `999`, `12.3k`, `4.5M`.
+pub fn compact_count(n: u64) -> String {
+    match n {
+        0..=999 => n.to_string(),
+        // Rounds up to "1.0M" rather than showing "1000.0k".
+        1_000..=999_949 => format!("{:.1}k", n as f64 / 1e3),
+        _ => format!("{:.1}M", n as f64 / 1e6),
+    }
+}
+
+/// A "downloads" badge for `project`.
+pub fn downloads(project: &Project) -> String {
+    svg("downloads", &compact_count(project.downloads), BLUE)
+}
+
+/// A "version" badge for `version`: green for releases, orange otherwise.
+pub fn latest_version(version: &ProjectVersion) -> String {
+    let color = if version.release_type == "release" {
+        GREEN
+    } else {
+        ORANGE
+    };
+    svg("version", &version.version, color)
+}
+
+/// Markdown that embeds the badge image at `image_url`, linking to `link`.
+pub fn markdown(alt: &str, image_url: &str, link: &str) -> String {
+    format!("[![{alt}]({image_url})]({link})")
+}
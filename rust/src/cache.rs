@@ -0,0 +1,121 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+// ---------------------------------------------------------------------------
+// Persistent response cache
+// ---------------------------------------------------------------------------
+
+/// How [`HubClient::disk_cache`](crate::HubClient::disk_cache) uses stored
+/// responses.
+///
+/// The default always asks the server and falls back to cached data of any
+/// age when the server cannot be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CachePolicy {
+    /// Serve cached responses younger than this without contacting the
+    /// server.
+    pub fresh_for: Duration,
+    /// Oldest cached response to serve when the server cannot be reached
+    /// (transport error, 5xx or open circuit); `None` accepts any age.
+    pub max_stale: Option<Duration>,
+    /// Never contact the server: serve everything from the cache and fail
+    /// with a `NotConnected` [`HubApiError::Io`](crate::HubApiError::Io)
+    /// (a network error) on a miss.
+    pub offline: bool,
+}
+
+impl CachePolicy {
+    /// Serve only what is already cached.
+    pub fn offline() -> Self {
+        Self {
+            offline: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// The per-user cache directory for the crate: `$XDG_CACHE_HOME/hub01`,
+/// `~/.cache/hub01`, or `%LOCALAPPDATA%\hub01` on Windows.
+pub fn default_dir() -> Option<PathBuf> {
+    let non_empty = |var| std::env::var_os(var).filter(|v| !v.is_empty());
+    non_empty("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty("HOME").map(|home| Path::new(&home).join(".cache")))
+        .or_else(|| non_empty("LOCALAPPDATA").map(PathBuf::from))
+        .map(|dir| dir.join("hub01"))
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct CacheEntry {
+    url: String,
+    /// Seconds since the Unix epoch.
+    stored_at: u64,
+    pub(crate) body: Option<serde_json::Value>,
+}
+
+impl CacheEntry {
+    pub(crate) fn age(&self) -> Duration {
+        Duration::from_secs(now().saturating_sub(self.stored_at))
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Decoded GET responses stored as one JSON file per URL.
+pub(crate) struct DiskCache {
+    dir: PathBuf,
+    pub(crate) policy: CachePolicy,
+}
+
+impl DiskCache {
+    pub(crate) fn new(dir: PathBuf, policy: CachePolicy) -> Self {
+        Self { dir, policy }
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        // FNV-1a, so file names stay the same across Rust versions.
+        let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+            (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.dir.join(format!("{hash:016x}.json"))
+    }
+
+    /// The stored response for `url`, if any.  Unreadable entries are
+    /// treated as missing.
+    pub(crate) fn load(&self, url: &str) -> Option<CacheEntry> {
+        let bytes = fs::read(self.path(url)).ok()?;
+        serde_json::from_slice::<CacheEntry>(&bytes)
+            .ok()
+            .filter(|entry| entry.url == url)
+    }
+
+    /// Store a response, replacing the file atomically.
+    pub(crate) fn store(&self, url: &str, body: &Option<serde_json::Value>) -> io::Result<()> {
+        let entry = CacheEntry {
+            url: url.to_string(),
+            stored_at: now(),
+            body: body.clone(),
+        };
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(url);
+        // Unique per write, so threads storing the same URL never share a
+        // temporary file.
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+        let tmp = path.with_extension(format!(
+            "tmp{}-{}",
+            std::process::id(),
+            WRITES.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&tmp, serde_json::to_vec(&entry).map_err(io::Error::other)?)?;
+        fs::rename(tmp, path)
+    }
+}
@@ -0,0 +1,265 @@
+//! Rendering of version changelogs, which the API stores as Markdown.
+//!
+//! Available with the `markdown` feature.
+//!
+//! ```
+//! use hub01_client::changelog;
+//!
+//! let md = "## Fixes\n\n- Crash on **startup**";
+//! assert_eq!(changelog::to_plain_text(md), "Fixes\n\n- Crash on startup");
+//! assert!(changelog::to_html(md).contains("<strong>startup</strong>"));
+//! ```
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+const BOLD: &str = "\x1b[1m";
+const ITALIC: &str = "\x1b[3m";
+const UNDERLINE: &str = "\x1b[4m";
+const STRIKE: &str = "\x1b[9m";
+const CYAN: &str = "\x1b[36m";
+const RESET: &str = "\x1b[0m";
+
+fn parser(markdown: &str) -> Parser<'_> {
+    Parser::new_ext(markdown, Options::ENABLE_STRIKETHROUGH)
+}
+
+/// Render a changelog to an HTML fragment.
+///
+/// Changelogs are user-written, so the output is safe to embed in a page:
+/// raw HTML is escaped rather than passed through, and links or images
+/// whose scheme is not `http`, `https` or `mailto` (or a relative path)
+/// lose their target.
+///
+/// ```
+/// use hub01_client::changelog;
+///
+/// let html = changelog::to_html("<script>alert(1)</script>\n\n[x](javascript:alert(1))");
+/// assert!(html.contains("&lt;script&gt;"));
+/// assert!(!html.contains("<script>"));
+/// assert!(html.contains(r#"<a href="">x</a>"#));
+/// assert!(!changelog::to_html("![x](data:text/html,hi)").contains("data:"));
+/// assert!(changelog::to_html("[docs](https://example.com/a:b)").contains("https://example.com/a:b"));
+/// assert!(changelog::to_html("[notes](docs/a:b.md)").contains("docs/a:b.md"));
+/// ```
+pub fn to_html(markdown: &str) -> String {
+    let events = parser(markdown).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Image {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        other => other,
+    });
+    let mut out = String::new();
+    html::push_html(&mut out, events);
+    out
+}
+
+/// `url` if it is relative or uses an allowed scheme, otherwise empty.
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    // Browsers ignore whitespace and control characters inside a scheme
+    // (`java\tscript:`), so look at the URL without them.
+    let compact: String = url
+        .chars()
+        .filter(|c| !c.is_whitespace() && !c.is_control())
+        .collect();
+    let scheme = compact
+        .split_once(':')
+        .map(|(scheme, _)| scheme)
+        .filter(|scheme| !scheme.contains(['/', '?', '#']));
+    match scheme {
+        None => url,
+        Some(scheme)
+            if ["http", "https", "mailto"]
+                .iter()
+                .any(|allowed| scheme.eq_ignore_ascii_case(allowed)) =>
+        {
+            url
+        }
+        Some(_) => CowStr::Borrowed(""),
+    }
+}
+
+/// Render a changelog to plain text: markup is dropped, list items keep a
+/// `- ` / `1. ` marker and links are followed by their URL.
+/// Control characters are dropped as for [`to_ansi`].
+pub fn to_plain_text(markdown: &str) -> String {
+    render(markdown, false)
+}
+
+/// Render a changelog for a terminal, using ANSI escapes for headings,
+/// emphasis and code.
+///
+/// Control characters other than newlines and tabs are dropped from the
+/// changelog itself, so it cannot smuggle in escape sequences of its own:
+///
+/// ```
+/// use hub01_client::changelog;
+///
+/// let md = "Fixed \x1b]0;pwned\x07crash\tin `\u{9b}2J`";
+/// assert_eq!(changelog::to_plain_text(md), "Fixed ]0;pwnedcrash\tin 2J");
+/// assert!(!changelog::to_ansi(md).contains(['\x07', '\u{9b}']));
+/// ```
+pub fn to_ansi(markdown: &str) -> String {
+    render(markdown, true)
+}
+
+struct Renderer {
+    ansi: bool,
+    out: String,
+    styles: Vec<&'static str>,
+    /// Next number for each open list (`None` for bullet lists).
+    lists: Vec<Option<u64>>,
+    links: Vec<String>,
+}
+
+impl Renderer {
+    /// Append changelog text, dropping control characters (ESC, BEL, C1...)
+    /// except newlines and tabs.
+    fn push_text(&mut self, text: &str) {
+        self.out.extend(
+            text.chars()
+                .filter(|&c| !c.is_control() || c == '\n' || c == '\t'),
+        );
+    }
+
+    fn push_style(&mut self, style: &'static str) {
+        if self.ansi {
+            self.styles.push(style);
+            self.out.push_str(style);
+        }
+    }
+
+    fn pop_style(&mut self) {
+        if self.ansi {
+            self.styles.pop();
+            self.out.push_str(RESET);
+            for style in &self.styles {
+                self.out.push_str(style);
+            }
+        }
+    }
+
+    /// End the current block with a blank line, unless inside a list where
+    /// items are kept on consecutive lines.
+    fn end_block(&mut self) {
+        self.out.push('\n');
+        if self.lists.is_empty() {
+            self.out.push('\n');
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading { .. } => {
+                self.push_style(BOLD);
+                self.push_style(UNDERLINE);
+            }
+            Tag::Emphasis => self.push_style(ITALIC),
+            Tag::Strong => self.push_style(BOLD),
+            Tag::Strikethrough => self.push_style(STRIKE),
+            Tag::CodeBlock(_) => self.push_style(CYAN),
+            Tag::Link { dest_url, .. } => {
+                self.links.push(dest_url.into_string());
+                self.push_style(UNDERLINE);
+            }
+            Tag::List(start) => {
+                if !self.lists.is_empty() && !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                let depth = self.lists.len().saturating_sub(1);
+                self.out.push_str(&"  ".repeat(depth));
+                match self.lists.last_mut() {
+                    Some(Some(n)) => {
+                        self.out.push_str(&format!("{n}. "));
+                        *n += 1;
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Heading(_) => {
+                self.pop_style();
+                self.pop_style();
+                self.end_block();
+            }
+            // Paragraphs inside loose list items end with the item instead.
+            TagEnd::Paragraph if self.lists.is_empty() => self.end_block(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::CodeBlock => {
+                if self.out.ends_with('\n') {
+                    self.out.pop();
+                }
+                self.pop_style();
+                self.end_block();
+            }
+            TagEnd::Link => {
+                self.pop_style();
+                if let Some(url) = self.links.pop() {
+                    self.push_text(&format!(" ({url})"));
+                }
+            }
+            TagEnd::Item if !self.out.ends_with('\n') => self.out.push('\n'),
+            TagEnd::List(_) => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.out.push('\n');
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render(markdown: &str, ansi: bool) -> String {
+    let mut r = Renderer {
+        ansi,
+        out: String::new(),
+        styles: Vec::new(),
+        lists: Vec::new(),
+        links: Vec::new(),
+    };
+    for event in parser(markdown) {
+        match event {
+            Event::Start(tag) => r.start(tag),
+            Event::End(tag) => r.end(tag),
+            Event::Text(text) => r.push_text(&text),
+            Event::Code(code) => {
+                r.push_style(CYAN);
+                r.push_text(&code);
+                r.pop_style();
+            }
+            Event::SoftBreak => r.out.push(' '),
+            Event::HardBreak => r.out.push('\n'),
+            Event::Rule => r.out.push_str("----\n\n"),
+            _ => {}
+        }
+    }
+    r.out.trim_end().to_string()
+}
@@ -1,35 +1,403 @@
+use std::fmt;
+use std::time::Duration;
+
 use thiserror::Error;
 
+use crate::slug::ProjectSlug;
+
 /// Error type for Hub01 Shop API operations.
 ///
-/// - `RequestFailed` — network/transport errors (wraps `reqwest::Error`)
+/// - `RequestFailed` — network/transport errors (wraps `reqwest::Error`; only
+///   with the `http` feature)
 /// - `Authentication` — HTTP 401
 /// - `PermissionDenied` — HTTP 403
-/// - `NotFound` — HTTP 404
+/// - `NotFound` — HTTP 404, with close project slugs when suggestions are on
 /// - `Validation` — HTTP 422, carries optional field-level errors
+/// - `RateLimited` — HTTP 429, with the server's `Retry-After` delay if given
+/// - `Maintenance` — HTTP 503 with a body: the instance is in maintenance mode
 /// - `Api` — any other non-2xx status code
+/// - `CircuitOpen` — request not sent because the circuit breaker is open
+/// - `ReadOnly` — mutating request refused by a read-only client
+/// - `ChecksumMismatch` — a downloaded file does not match its published SHA-1
+/// - `Io` — I/O failure in a custom transport, while reading a response body
+///   or while writing it out
+///
+/// Errors built from an HTTP error response keep the exchange that caused
+/// them; see [`HubApiError::response`].
 #[derive(Debug, Error)]
 pub enum HubApiError {
+    #[cfg(feature = "http")]
     #[error("Request failed: {0}")]
     RequestFailed(#[from] reqwest::Error),
 
     #[error("Authentication failed: {message}")]
-    Authentication { message: String },
+    Authentication {
+        message: String,
+        response: Option<Box<ErrorResponse>>,
+    },
 
     #[error("Permission denied: {message}")]
-    PermissionDenied { message: String },
+    PermissionDenied {
+        message: String,
+        response: Option<Box<ErrorResponse>>,
+    },
 
     #[error("Not found: {message}")]
-    NotFound { message: String },
+    NotFound {
+        message: String,
+        /// Similar project slugs, filled in by `projects().get` when
+        /// `HubClient::suggest_similar` is enabled.
+        suggestions: Vec<ProjectSlug>,
+        response: Option<Box<ErrorResponse>>,
+    },
 
     #[error("Validation error: {message}")]
     Validation {
         message: String,
         errors: Option<serde_json::Value>,
+        response: Option<Box<ErrorResponse>>,
+    },
+
+    #[error(
+        "Rate limited by the server{}",
+        retry_after.map(|d| format!(", retry in {}s", d.as_secs())).unwrap_or_default()
+    )]
+    RateLimited {
+        retry_after: Option<Duration>,
+        response: Option<Box<ErrorResponse>>,
+    },
+
+    #[error("Server under maintenance: {message}")]
+    Maintenance {
+        message: String,
+        retry_after: Option<Duration>,
+        response: Option<Box<ErrorResponse>>,
     },
 
     #[error("API error {status}: {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        message: String,
+        response: Option<Box<ErrorResponse>>,
+    },
+
+    #[error("Circuit open: server marked unavailable, retry in {}s", retry_after.as_secs())]
+    CircuitOpen { retry_after: Duration },
+
+    #[error("Read-only client: refusing {method} {endpoint}")]
+    ReadOnly {
+        method: &'static str,
+        endpoint: String,
+    },
+
+    #[error("Checksum mismatch: expected SHA-1 {expected}, got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl HubApiError {
+    /// The HTTP exchange the error was built from, if it came from an error
+    /// response of the server (rather than from the client itself).
+    pub fn response(&self) -> Option<&ErrorResponse> {
+        match self {
+            HubApiError::Authentication { response, .. }
+            | HubApiError::PermissionDenied { response, .. }
+            | HubApiError::NotFound { response, .. }
+            | HubApiError::Validation { response, .. }
+            | HubApiError::RateLimited { response, .. }
+            | HubApiError::Maintenance { response, .. }
+            | HubApiError::Api { response, .. } => response.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Attach the exchange an error response came from.
+    #[cfg(feature = "http")]
+    pub(crate) fn with_response(mut self, details: ErrorResponse) -> Self {
+        match &mut self {
+            HubApiError::Authentication { response, .. }
+            | HubApiError::PermissionDenied { response, .. }
+            | HubApiError::NotFound { response, .. }
+            | HubApiError::Validation { response, .. }
+            | HubApiError::RateLimited { response, .. }
+            | HubApiError::Maintenance { response, .. }
+            | HubApiError::Api { response, .. } => *response = Some(Box::new(details)),
+            _ => {}
+        }
+        self
+    }
+
+    /// A short, user-facing suggestion for how to resolve the error, suitable
+    /// for showing under the error message in a CLI or UI.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "http")]
+            HubApiError::RequestFailed(_) => {
+                "Check your network connection and the instance URL."
+            }
+            HubApiError::Authentication { .. } => {
+                "Check that the API token is correct and has not expired."
+            }
+            HubApiError::PermissionDenied { .. } => {
+                "The API token lacks permission for this action; use a token of a project member with the required rights."
+            }
+            HubApiError::NotFound { .. } => {
+                "Check the spelling of the slug or version; identifiers are case-sensitive."
+            }
+            HubApiError::Validation { .. } => "Fix the fields listed in the error details and retry.",
+            HubApiError::RateLimited { .. } => {
+                "Too many requests; wait before retrying or enable HubClient::wait_on_rate_limit."
+            }
+            HubApiError::Maintenance { .. } => {
+                "The server is under maintenance; try again later."
+            }
+            HubApiError::Api { status: 500.., .. } => {
+                "The server had an internal error; try again later."
+            }
+            HubApiError::Api { .. } => {
+                "Unexpected response from the server; the instance may run an incompatible API version."
+            }
+            HubApiError::CircuitOpen { .. } => {
+                "The server failed repeatedly; wait for the cool-down before retrying."
+            }
+            HubApiError::ReadOnly { .. } => {
+                "The client is in read-only mode; create it without read_only(true) to modify data."
+            }
+            HubApiError::ChecksumMismatch { .. } => {
+                "The download is corrupt or was altered in transit; download it again."
+            }
+            HubApiError::Io(e) if is_connection_error(e) => {
+                "Check your network connection and the instance URL."
+            }
+            HubApiError::Io(_) => "Check free disk space and file permissions.",
+        }
+    }
+
+    /// Process exit code for command-line tools, following the BSD
+    /// `sysexits.h` conventions.
+    ///
+    /// | Error                                | Code | Name             |
+    /// |--------------------------------------|------|------------------|
+    /// | `Validation`                         | 65   | `EX_DATAERR`     |
+    /// | `NotFound`                           | 66   | `EX_NOINPUT`     |
+    /// | `RequestFailed`, `CircuitOpen`,      | 69   | `EX_UNAVAILABLE` |
+    /// | `Maintenance`, 5xx, connection `Io`  |      |                  |
+    /// | other `Io`, `ChecksumMismatch`       | 74   | `EX_IOERR`       |
+    /// | `RateLimited`                        | 75   | `EX_TEMPFAIL`    |
+    /// | other `Api` errors                   | 76   | `EX_PROTOCOL`    |
+    /// | `Authentication`, `PermissionDenied` | 77   | `EX_NOPERM`      |
+    /// | `ReadOnly`                           | 78   | `EX_CONFIG`      |
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            HubApiError::Validation { .. } => 65,
+            HubApiError::NotFound { .. } => 66,
+            #[cfg(feature = "http")]
+            HubApiError::RequestFailed(_) => 69,
+            HubApiError::Io(e) if is_connection_error(e) => 69,
+            HubApiError::CircuitOpen { .. }
+            | HubApiError::Maintenance { .. }
+            | HubApiError::Api { status: 500.., .. } => 69,
+            HubApiError::Io(_) | HubApiError::ChecksumMismatch { .. } => 74,
+            HubApiError::RateLimited { .. } => 75,
+            HubApiError::Api { .. } => 76,
+            HubApiError::Authentication { .. } | HubApiError::PermissionDenied { .. } => 77,
+            HubApiError::ReadOnly { .. } => 78,
+        }
+    }
+
+    /// The broad category of the error, for branching without matching on
+    /// every variant.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            #[cfg(feature = "http")]
+            HubApiError::RequestFailed(_) => ErrorKind::Network,
+            HubApiError::Io(e) if is_connection_error(e) => ErrorKind::Network,
+            HubApiError::Io(_) => ErrorKind::Io,
+            HubApiError::Authentication { .. } => ErrorKind::Authentication,
+            HubApiError::PermissionDenied { .. } => ErrorKind::PermissionDenied,
+            HubApiError::NotFound { .. } => ErrorKind::NotFound,
+            HubApiError::Validation { .. } => ErrorKind::Validation,
+            HubApiError::RateLimited { .. } => ErrorKind::RateLimited,
+            HubApiError::Maintenance { .. }
+            | HubApiError::CircuitOpen { .. }
+            | HubApiError::Api {
+                status: 502..=504, ..
+            } => ErrorKind::Unavailable,
+            HubApiError::Api { status: 500.., .. } => ErrorKind::Server,
+            HubApiError::Api { .. } => ErrorKind::Protocol,
+            HubApiError::ReadOnly { .. } => ErrorKind::ReadOnly,
+            HubApiError::ChecksumMismatch { .. } => ErrorKind::Integrity,
+        }
+    }
+
+    /// The HTTP status of the server response behind the error, if there
+    /// was one.  Errors raised by the client itself (e.g. parameter
+    /// validation) have none.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            #[cfg(feature = "http")]
+            HubApiError::RequestFailed(e) => e.status().map(|s| s.as_u16()),
+            HubApiError::Api { status, .. } if *status != 0 => Some(*status),
+            _ => self.response().map(|r| r.status),
+        }
+    }
+
+    /// Whether the same request may succeed if sent again later: network
+    /// failures, rate limiting and an unavailable server.  Local I/O errors
+    /// such as a full disk or a denied permission are not retryable.
+    ///
+    /// Unlike [`RetryPolicy::is_transient`](crate::RetryPolicy::is_transient),
+    /// which decides on immediate retries, this includes rate limiting and
+    /// an open circuit breaker, which need a longer wait first.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind(),
+            ErrorKind::Network | ErrorKind::RateLimited | ErrorKind::Unavailable
+        )
+    }
+
+    /// Whether the error is about the credentials: a missing, invalid or
+    /// expired token, or insufficient permissions.
+    pub fn is_auth(&self) -> bool {
+        matches!(
+            self.kind(),
+            ErrorKind::Authentication | ErrorKind::PermissionDenied
+        )
+    }
+}
+
+/// Broad category of a [`HubApiError`], returned by [`HubApiError::kind`].
+///
+/// | Kind               | Errors                                             |
+/// |--------------------|----------------------------------------------------|
+/// | `Network`          | `RequestFailed`, `Io` from the connection (reset,  |
+/// |                    | refused, timed out, cut short)                     |
+/// | `Io`               | other `Io` errors, e.g. local files                |
+/// | `Authentication`   | `Authentication`                                   |
+/// | `PermissionDenied` | `PermissionDenied`                                 |
+/// | `NotFound`         | `NotFound`                                         |
+/// | `Validation`       | `Validation`                                       |
+/// | `RateLimited`      | `RateLimited`                                      |
+/// | `Unavailable`      | `Maintenance`, `CircuitOpen`, `Api` 502–504        |
+/// | `Server`           | other 5xx `Api` errors                             |
+/// | `Protocol`         | other `Api` errors, e.g. undecodable responses     |
+/// | `ReadOnly`         | `ReadOnly`                                         |
+/// | `Integrity`        | `ChecksumMismatch`                                 |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    Network,
+    Io,
+    Authentication,
+    PermissionDenied,
+    NotFound,
+    Validation,
+    RateLimited,
+    Unavailable,
+    Server,
+    Protocol,
+    ReadOnly,
+    Integrity,
 }
 
 pub type Result<T> = std::result::Result<T, HubApiError>;
+
+/// Whether an I/O error comes from the connection rather than from local
+/// files, e.g. one raised by a custom transport.
+fn is_connection_error(e: &std::io::Error) -> bool {
+    use std::io::ErrorKind::*;
+    matches!(
+        e.kind(),
+        ConnectionRefused
+            | ConnectionReset
+            | ConnectionAborted
+            | BrokenPipe
+            | NotConnected
+            | TimedOut
+            | UnexpectedEof
+            | Interrupted
+    )
+}
+
+/// Response headers kept in [`ErrorResponse::headers`] (lowercase).  Others,
+/// such as `set-cookie`, are dropped.
+pub const ERROR_RESPONSE_HEADERS: &[&str] = &[
+    "content-type",
+    "date",
+    "server",
+    "retry-after",
+    "www-authenticate",
+    "x-request-id",
+    "x-correlation-id",
+    "cf-ray",
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+];
+
+/// At most this many bytes of an error response body are kept.
+pub const MAX_ERROR_RESPONSE_BODY: usize = 64 * 1024;
+
+/// A failed request and the server's answer, kept on errors for debugging
+/// (e.g. the HTML or stack trace behind a 500).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorResponse {
+    pub method: String,
+    pub url: String,
+    pub status: u16,
+    /// The headers named in [`ERROR_RESPONSE_HEADERS`], in response order.
+    pub headers: Vec<(String, String)>,
+    /// The body, cut at [`MAX_ERROR_RESPONSE_BODY`] bytes; invalid UTF-8 is
+    /// replaced.
+    pub body: String,
+}
+
+impl ErrorResponse {
+    #[cfg(feature = "http")]
+    pub(crate) fn new(
+        method: &str,
+        url: &str,
+        status: u16,
+        headers: &[(String, String)],
+        body: &[u8],
+    ) -> Self {
+        Self {
+            method: method.to_string(),
+            url: url.to_string(),
+            status,
+            headers: headers
+                .iter()
+                .filter(|(name, _)| {
+                    ERROR_RESPONSE_HEADERS
+                        .iter()
+                        .any(|kept| name.eq_ignore_ascii_case(kept))
+                })
+                .cloned()
+                .collect(),
+            body: String::from_utf8_lossy(&body[..body.len().min(MAX_ERROR_RESPONSE_BODY)])
+                .into_owned(),
+        }
+    }
+
+    /// Value of the first kept header named `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl fmt::Display for ErrorResponse {
+    /// `METHOD url -> status`, followed by the headers and body.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {} -> {}", self.method, self.url, self.status)?;
+        for (name, value) in &self.headers {
+            writeln!(f, "{name}: {value}")?;
+        }
+        if !self.body.is_empty() {
+            write!(f, "\n{}", self.body)?;
+        }
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@
 //! # Quick Start
 //!
 //! ```no_run
+//! # #[cfg(feature = "http")] {
 //! use hub01_client::HubClient;
 //!
 //! let client = HubClient::new("https://hub01-shop.srgnis.com/api", None).unwrap();
@@ -16,20 +17,64 @@
 //! for t in &types {
 //!     println!("{}: {}", t.name, t.slug);
 //! }
+//! # }
 //! ```
+//!
+//! For scripts, `use hub01_client::prelude::*;` brings the client, parameter
+//! types, models, identifiers and the `Result` alias into scope at once.
+//!
+//! The HTTP clients need the `http` feature, which the default TLS features
+//! enable.  With `default-features = false` only the models, parameters,
+//! identifiers and errors are built, without depending on reqwest.
 
+pub mod archive;
+#[cfg(feature = "async")]
+pub mod async_client;
+pub mod badge;
+#[cfg(feature = "http")]
+pub mod cache;
+#[cfg(feature = "markdown")]
+pub mod changelog;
+#[cfg(feature = "http")]
+mod checksum;
+#[cfg(feature = "http")]
 pub mod client;
 pub mod error;
 pub mod models;
+pub mod params;
+pub mod prelude;
+pub mod progress;
+pub mod slug;
+pub mod tags;
+#[cfg(feature = "http")]
+pub mod transport;
+pub mod versions;
 
 // Re-export the main public types at the crate root for convenience.
+pub use archive::ArchiveEntry;
+#[cfg(feature = "async")]
+pub use async_client::AsyncHubClient;
+#[cfg(feature = "http")]
+pub use cache::CachePolicy;
+#[cfg(feature = "http")]
 pub use client::{
-    CreateVersionParams, Dependency, HubClient, ListProjectsParams, ListVersionsParams,
-    ProjectTypesClient, ProjectVersionsClient, ProjectsClient, TagsClient, UpdateVersionParams,
-    UsersClient,
+    AuditEvent, ClientMetrics, FilesClient, HubClient, HubClientBuilder, ProjectHandle,
+    ProjectTypesClient, ProjectVersionsClient, ProjectsClient, RetryPolicy, TagsClient,
+    UsersClient, VersionHandle, MAX_SUGGESTIONS,
 };
-pub use error::HubApiError;
+pub use error::{ErrorKind, ErrorResponse, HubApiError};
 pub use models::{
-    PaginatedResponse, Project, ProjectFile, ProjectTag, ProjectType, ProjectVersion,
-    ProjectVersionDependency, ProjectVersionTag, User,
+    Capabilities, DependencyKind, PaginatedResponse, Project, ProjectFile, ProjectTag, ProjectType,
+    ProjectVersion, ProjectVersionDependency, ProjectVersionTag, User, VersionChannel,
+};
+pub use params::{
+    CreateVersionParams, Dependency, ListProjectsParams, ListProjectsParamsBuilder,
+    ListVersionsParams, ProjectOrderBy, ReleaseDatePeriod, SortDirection, UpdateVersionParams,
+    VersionOrderBy, MAX_PER_PAGE, PER_PAGE_OPTIONS,
+};
+pub use slug::{ProjectSlug, TagSlug, Username, VersionSlug};
+pub use tags::TagResolver;
+#[cfg(feature = "http")]
+pub use transport::{
+    FileSource, HttpRequest, HttpResponse, RequestBody, ReqwestTransport, Transport,
 };
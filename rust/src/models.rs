@@ -1,5 +1,32 @@
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::Deserialize;
 
+use crate::error::HubApiError;
+use crate::slug::{ProjectSlug, TagSlug, Username, VersionSlug};
+
+/// Parse a date as the API formats it: RFC 3339 timestamps, plain
+/// `YYYY-MM-DD HH:MM:SS` (taken as UTC) or bare `YYYY-MM-DD` dates
+/// (midnight UTC).
+#[cfg(feature = "chrono")]
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|d| d.and_utc())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| d.and_utc())
+        })
+}
+
 // ---------------------------------------------------------------------------
 // Generic paginated response wrapper
 // ---------------------------------------------------------------------------
@@ -16,6 +43,36 @@ pub struct PaginatedResponse<T> {
     pub links: Option<serde_json::Value>,
 }
 
+impl<T> PaginatedResponse<T> {
+    /// URL of the next page as given in `links.next`, if there is one.
+    pub fn next_url(&self) -> Option<&str> {
+        self.link("next")
+    }
+
+    /// URL of the previous page as given in `links.prev`, if there is one.
+    pub fn prev_url(&self) -> Option<&str> {
+        self.link("prev")
+    }
+
+    /// Page number of this response, from `meta.current_page`.
+    pub fn current_page(&self) -> Option<u64> {
+        self.meta_u64("current_page")
+    }
+
+    /// Number of the last page, from `meta.last_page`.
+    pub fn last_page(&self) -> Option<u64> {
+        self.meta_u64("last_page")
+    }
+
+    fn link(&self, name: &str) -> Option<&str> {
+        self.links.as_ref()?.get(name)?.as_str()
+    }
+
+    fn meta_u64(&self, name: &str) -> Option<u64> {
+        self.meta.as_ref()?.get(name)?.as_u64()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Project types
 // ---------------------------------------------------------------------------
@@ -34,7 +91,7 @@ pub struct ProjectType {
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProjectTag {
     pub name: String,
-    pub slug: String,
+    pub slug: TagSlug,
     pub icon: String,
     pub tag_group: Option<String>,
     pub project_types: Vec<String>,
@@ -46,7 +103,7 @@ pub struct ProjectTag {
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProjectVersionTag {
     pub name: String,
-    pub slug: String,
+    pub slug: TagSlug,
     pub icon: String,
     pub tag_group: Option<String>,
     pub project_types: Vec<String>,
@@ -62,7 +119,7 @@ pub struct ProjectVersionTag {
 #[derive(Debug, Clone, Deserialize)]
 pub struct Project {
     pub name: String,
-    pub slug: String,
+    pub slug: ProjectSlug,
     pub summary: String,
     pub description: Option<String>,
     pub logo_url: String,
@@ -83,6 +140,23 @@ pub struct Project {
     pub members: Vec<serde_json::Value>,
 }
 
+/// Parsed date fields; `None` when the field is absent or not a date the
+/// API is known to send.
+#[cfg(feature = "chrono")]
+impl Project {
+    pub fn created_at_utc(&self) -> Option<DateTime<Utc>> {
+        parse_date(&self.created_at)
+    }
+
+    pub fn updated_at_utc(&self) -> Option<DateTime<Utc>> {
+        self.updated_at.as_deref().and_then(parse_date)
+    }
+
+    pub fn last_release_date_utc(&self) -> Option<DateTime<Utc>> {
+        self.last_release_date.as_deref().and_then(parse_date)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Project files
 // ---------------------------------------------------------------------------
@@ -99,15 +173,65 @@ pub struct ProjectFile {
 // Dependencies
 // ---------------------------------------------------------------------------
 
+/// How a version relates to one of its dependencies.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DependencyKind {
+    Required,
+    Optional,
+    Embedded,
+    Incompatible,
+    /// A kind this client does not know yet, so newer servers still decode.
+    /// The server's value is kept and sent back unchanged.
+    Unknown(String),
+}
+
+impl DependencyKind {
+    /// The value used by the API for this kind.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DependencyKind::Required => "required",
+            DependencyKind::Optional => "optional",
+            DependencyKind::Embedded => "embedded",
+            DependencyKind::Incompatible => "incompatible",
+            DependencyKind::Unknown(value) => value,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DependencyKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Ok(match value.as_str() {
+            "required" => DependencyKind::Required,
+            "optional" => DependencyKind::Optional,
+            "embedded" => DependencyKind::Embedded,
+            "incompatible" => DependencyKind::Incompatible,
+            _ => DependencyKind::Unknown(value),
+        })
+    }
+}
+
+impl std::fmt::Display for DependencyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProjectVersionDependency {
     #[serde(rename = "project")]
-    pub project_slug: String,
+    pub project_slug: ProjectSlug,
     #[serde(rename = "version")]
-    pub version_slug: Option<String>,
+    pub version_slug: Option<VersionSlug>,
     #[serde(rename = "type")]
-    pub dep_type: String,
+    pub dep_type: DependencyKind,
     pub external: bool,
+    /// Display name of an external dependency.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Homepage / download URL of an external dependency.
+    #[serde(default)]
+    pub url: Option<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -117,7 +241,7 @@ pub struct ProjectVersionDependency {
 #[derive(Debug, Clone, Deserialize)]
 pub struct ProjectVersion {
     pub name: String,
-    pub version: String,
+    pub version: VersionSlug,
     pub release_type: String,
     pub release_date: String,
     pub changelog: Option<String>,
@@ -130,14 +254,128 @@ pub struct ProjectVersion {
     pub dependencies: Vec<ProjectVersionDependency>,
 }
 
+impl ProjectVersion {
+    /// Whether `query` appears, ignoring case, in the version's name,
+    /// version string or changelog.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        [
+            Some(self.name.as_str()),
+            Some(self.version.as_str()),
+            self.changelog.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|field| field.to_lowercase().contains(&query))
+    }
+
+    /// The version string as a semantic version.  A leading `v` is ignored
+    /// and missing minor/patch numbers count as zero (`v1.2` is `1.2.0`);
+    /// `None` for schemes that are not semver-like, e.g. `2024w10a`.
+    #[cfg(feature = "semver")]
+    pub fn semver(&self) -> Option<semver::Version> {
+        parse_semver(&self.version)
+    }
+
+    /// The release date parsed as midnight UTC (or the exact time, if the
+    /// API sends one).
+    #[cfg(feature = "chrono")]
+    pub fn release_date_utc(&self) -> Option<DateTime<Utc>> {
+        parse_date(&self.release_date)
+    }
+}
+
+#[cfg(feature = "semver")]
+fn parse_semver(version: &str) -> Option<semver::Version> {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    if let Ok(parsed) = semver::Version::parse(version) {
+        return Some(parsed);
+    }
+    // Pad `1` / `1.2` (optionally with a pre-release or build suffix).
+    let split = version.find(['-', '+']).unwrap_or(version.len());
+    let (core, suffix) = version.split_at(split);
+    let parts = core.split('.').count();
+    if parts >= 3
+        || !core
+            .split('.')
+            .all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+    let padding = ".0".repeat(3 - parts);
+    semver::Version::parse(&format!("{core}{padding}{suffix}")).ok()
+}
+
+/// A moving alias for a project version, resolved client-side by
+/// `ProjectVersionsClient::resolve`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VersionChannel {
+    /// The most recently released version of any release type.
+    Latest,
+    /// The most recently released version with release type `release`.
+    Stable,
+}
+
+impl VersionChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionChannel::Latest => "latest",
+            VersionChannel::Stable => "stable",
+        }
+    }
+
+    /// Whether `version` belongs to this channel.
+    pub fn includes(&self, version: &ProjectVersion) -> bool {
+        match self {
+            VersionChannel::Latest => true,
+            VersionChannel::Stable => version.release_type == "release",
+        }
+    }
+}
+
+impl std::fmt::Display for VersionChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for VersionChannel {
+    type Err = HubApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(VersionChannel::Latest),
+            "stable" => Ok(VersionChannel::Stable),
+            _ => Err(HubApiError::Validation {
+                message: format!("Unknown version channel '{s}': expected 'latest' or 'stable'"),
+                errors: None,
+                response: None,
+            }),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Users
 // ---------------------------------------------------------------------------
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct User {
-    pub username: String,
+    pub username: Username,
     pub bio: Option<String>,
     pub avatar: Option<String>,
     pub created_at: String,
 }
+
+// ---------------------------------------------------------------------------
+// Instance capabilities
+// ---------------------------------------------------------------------------
+
+/// What the connected instance reports about itself, as returned by
+/// [`HubClient::capabilities`](crate::HubClient::capabilities).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The configured token was accepted by the server.
+    pub authenticated: bool,
+}
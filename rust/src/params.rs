@@ -0,0 +1,624 @@
+//! Request parameters shared by the blocking and async clients.
+//!
+//! These types do not depend on the HTTP stack and are available without
+//! the `http` feature.
+
+// The request encoders are only called by the clients.
+#![cfg_attr(not(feature = "http"), allow(dead_code))]
+
+use crate::error::{HubApiError, Result};
+use crate::models::*;
+use crate::slug::VersionSlug;
+
+// ---------------------------------------------------------------------------
+// Pagination
+// ---------------------------------------------------------------------------
+
+/// Page sizes accepted by the API's `per_page` parameter.
+pub const PER_PAGE_OPTIONS: [u32; 4] = [10, 25, 50, 100];
+
+/// Largest page size the API will return.
+pub const MAX_PER_PAGE: u32 = 100;
+
+/// Reject pagination values the server would refuse (or silently truncate)
+/// before making a request.
+pub(crate) fn validate_pagination(per_page: u32, page: u32) -> Result<()> {
+    let mut errors = serde_json::Map::new();
+    if !PER_PAGE_OPTIONS.contains(&per_page) {
+        errors.insert(
+            "per_page".into(),
+            serde_json::json!([format!(
+                "per_page must be one of {PER_PAGE_OPTIONS:?} (got {per_page})"
+            )]),
+        );
+    }
+    if page == 0 {
+        errors.insert(
+            "page".into(),
+            serde_json::json!(["page numbers start at 1"]),
+        );
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(HubApiError::Validation {
+            message: "Invalid pagination parameters".into(),
+            errors: Some(serde_json::Value::Object(errors)),
+            response: None,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Projects
+// ---------------------------------------------------------------------------
+
+/// Parameters for listing / searching projects.
+///
+/// Build with struct syntax over `Default`, or with
+/// [`builder`](Self::builder):
+///
+/// ```
+/// use hub01_client::{ListProjectsParams, SortDirection};
+///
+/// let params = ListProjectsParams::builder()
+///     .project_type("mod")
+///     .tags(["api"])
+///     .order_direction(SortDirection::Asc)
+///     .page(2)
+///     .build();
+/// assert_eq!(params.tags, Some(vec!["api".to_string()]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ListProjectsParams {
+    pub project_type: Option<String>,
+    pub search: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub version_tags: Option<Vec<String>>,
+    pub order_by: ProjectOrderBy,
+    pub order_direction: SortDirection,
+    pub per_page: u32,
+    pub page: u32,
+    /// Omitted by default, leaving the server's own default (`all`).
+    pub release_date_period: Option<ReleaseDatePeriod>,
+}
+
+impl Default for ListProjectsParams {
+    fn default() -> Self {
+        Self {
+            project_type: Some("mod".into()),
+            search: None,
+            tags: None,
+            version_tags: None,
+            order_by: ProjectOrderBy::Downloads,
+            order_direction: SortDirection::Desc,
+            per_page: 10,
+            page: 1,
+            release_date_period: None,
+        }
+    }
+}
+
+impl ListProjectsParams {
+    /// Start from the defaults: mods, most downloaded first, 10 per page.
+    pub fn builder() -> ListProjectsParamsBuilder {
+        ListProjectsParamsBuilder {
+            params: Self::default(),
+        }
+    }
+
+    /// Check the parameters the server would reject, without sending a
+    /// request.  `list` does this automatically.
+    pub fn validate(&self) -> Result<()> {
+        validate_pagination(self.per_page, self.page)?;
+        match self.release_date_period {
+            Some(ref period) => period.validate(),
+            None => Ok(()),
+        }
+    }
+
+    /// Query string pairs for `GET /v1/projects`.
+    pub(crate) fn to_query(&self) -> Vec<(String, String)> {
+        let mut query: Vec<(String, String)> = Vec::new();
+
+        if let Some(ref v) = self.project_type {
+            query.push(("project_type".into(), v.clone()));
+        }
+        if let Some(ref v) = self.search {
+            query.push(("search".into(), v.clone()));
+        }
+        if let Some(ref tags) = self.tags {
+            for t in tags {
+                query.push(("tags[]".into(), t.clone()));
+            }
+        }
+        if let Some(ref tags) = self.version_tags {
+            for t in tags {
+                query.push(("version_tags[]".into(), t.clone()));
+            }
+        }
+        query.push(("order_by".into(), self.order_by.to_string()));
+        query.push(("order_direction".into(), self.order_direction.to_string()));
+        query.push(("per_page".into(), self.per_page.to_string()));
+        query.push(("page".into(), self.page.to_string()));
+        match self.release_date_period {
+            Some(ReleaseDatePeriod::All) => {
+                query.push(("release_date_period".into(), "all".into()));
+            }
+            Some(ReleaseDatePeriod::Custom { ref start, ref end }) => {
+                query.push(("release_date_start".into(), start.clone()));
+                query.push(("release_date_end".into(), end.clone()));
+            }
+            None => {}
+        }
+        query
+    }
+}
+
+/// Chained setters for [`ListProjectsParams`], created by
+/// [`ListProjectsParams::builder`].
+#[derive(Debug, Clone)]
+pub struct ListProjectsParamsBuilder {
+    params: ListProjectsParams,
+}
+
+impl ListProjectsParamsBuilder {
+    pub fn project_type(mut self, project_type: impl Into<String>) -> Self {
+        self.params.project_type = Some(project_type.into());
+        self
+    }
+
+    /// List projects of every type instead of only mods.
+    pub fn any_project_type(mut self) -> Self {
+        self.params.project_type = None;
+        self
+    }
+
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.params.search = Some(search.into());
+        self
+    }
+
+    /// Only list projects carrying all of `tags`.
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only list projects with a version carrying all of `tags`.
+    pub fn version_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.params.version_tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn order_by(mut self, order_by: ProjectOrderBy) -> Self {
+        self.params.order_by = order_by;
+        self
+    }
+
+    pub fn order_direction(mut self, direction: SortDirection) -> Self {
+        self.params.order_direction = direction;
+        self
+    }
+
+    /// One of [`PER_PAGE_OPTIONS`]; checked when listing.
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.params.per_page = per_page;
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.page = page;
+        self
+    }
+
+    pub fn release_date_period(mut self, period: ReleaseDatePeriod) -> Self {
+        self.params.release_date_period = Some(period);
+        self
+    }
+
+    /// Only list projects released between `start` and `end`
+    /// (`YYYY-MM-DD`), i.e. [`ReleaseDatePeriod::Custom`].
+    pub fn release_date_range(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.params.release_date_period = Some(ReleaseDatePeriod::Custom {
+            start: start.into(),
+            end: end.into(),
+        });
+        self
+    }
+
+    pub fn build(self) -> ListProjectsParams {
+        self.params
+    }
+}
+
+/// Release date filter for project listings.
+///
+/// Only the values the API documents are modelled; more may be added.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ReleaseDatePeriod {
+    /// Sent as `release_date_period=all`.
+    #[default]
+    All,
+    /// Released between `start` and `end`, both `YYYY-MM-DD` and inclusive.
+    /// Sent as `release_date_start` and `release_date_end`.
+    Custom { start: String, end: String },
+}
+
+impl ReleaseDatePeriod {
+    /// Check that a custom range has well-formed dates in order.
+    fn validate(&self) -> Result<()> {
+        let ReleaseDatePeriod::Custom { start, end } = self else {
+            return Ok(());
+        };
+        let is_date = |d: &str| {
+            d.len() == 10
+                && d.bytes().enumerate().all(|(i, b)| {
+                    if i == 4 || i == 7 {
+                        b == b'-'
+                    } else {
+                        b.is_ascii_digit()
+                    }
+                })
+        };
+        let mut errors = serde_json::Map::new();
+        for (field, value) in [("release_date_start", start), ("release_date_end", end)] {
+            if !is_date(value) {
+                errors.insert(
+                    field.into(),
+                    serde_json::json!([format!("{field} must be YYYY-MM-DD (got {value:?})")]),
+                );
+            }
+        }
+        if errors.is_empty() && start > end {
+            errors.insert(
+                "release_date_end".into(),
+                serde_json::json!([format!(
+                    "release_date_end {end} is before release_date_start {start}"
+                )]),
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(HubApiError::Validation {
+                message: "Invalid release date range".into(),
+                errors: Some(serde_json::Value::Object(errors)),
+                response: None,
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for ReleaseDatePeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReleaseDatePeriod::All => f.write_str("all"),
+            ReleaseDatePeriod::Custom { start, end } => write!(f, "{start}..{end}"),
+        }
+    }
+}
+
+/// Field to sort projects by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProjectOrderBy {
+    #[default]
+    Downloads,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+    LatestVersion,
+}
+
+impl ProjectOrderBy {
+    /// The value used by the API for this field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectOrderBy::Downloads => "downloads",
+            ProjectOrderBy::Name => "name",
+            ProjectOrderBy::CreatedAt => "created_at",
+            ProjectOrderBy::UpdatedAt => "updated_at",
+            ProjectOrderBy::LatestVersion => "latest_version",
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Project versions
+// ---------------------------------------------------------------------------
+
+/// Sort direction for project and version listings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortDirection {
+    Asc,
+    #[default]
+    Desc,
+}
+
+impl SortDirection {
+    /// The value used by the API for this direction.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "asc",
+            SortDirection::Desc => "desc",
+        }
+    }
+}
+
+impl std::fmt::Display for SortDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Field to sort project versions by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum VersionOrderBy {
+    #[default]
+    Downloads,
+    ReleaseDate,
+    Name,
+}
+
+impl VersionOrderBy {
+    /// The value used by the API for this field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VersionOrderBy::Downloads => "downloads",
+            VersionOrderBy::ReleaseDate => "release_date",
+            VersionOrderBy::Name => "name",
+        }
+    }
+}
+
+impl std::fmt::Display for VersionOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parameters for listing project versions.
+///
+/// Build with struct syntax over `Default`, or with the chained setters:
+///
+/// ```
+/// use hub01_client::{ListVersionsParams, SortDirection, VersionOrderBy};
+///
+/// let params = ListVersionsParams::new()
+///     .tags(["forge", "1.20"])
+///     .order_by(VersionOrderBy::ReleaseDate)
+///     .order_direction(SortDirection::Desc)
+///     .per_page(25);
+/// assert!(params.validate().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ListVersionsParams {
+    /// Free-text search, sent as `search`.  Instances that do not support
+    /// version search ignore it; use [`ProjectVersion::matches`] to filter
+    /// the returned page locally in that case.
+    pub search: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub order_by: VersionOrderBy,
+    pub order_direction: SortDirection,
+    pub per_page: u32,
+    pub page: u32,
+}
+
+impl Default for ListVersionsParams {
+    fn default() -> Self {
+        Self {
+            search: None,
+            tags: None,
+            order_by: VersionOrderBy::Downloads,
+            order_direction: SortDirection::Desc,
+            per_page: 10,
+            page: 1,
+        }
+    }
+}
+
+impl ListVersionsParams {
+    /// Same as [`Default::default`]: most downloaded first, 10 per page.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search versions by name, version string or changelog.
+    pub fn search(mut self, search: impl Into<String>) -> Self {
+        self.search = Some(search.into());
+        self
+    }
+
+    /// Only list versions carrying all of `tags`.
+    pub fn tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags = Some(tags.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn order_by(mut self, order_by: VersionOrderBy) -> Self {
+        self.order_by = order_by;
+        self
+    }
+
+    pub fn order_direction(mut self, direction: SortDirection) -> Self {
+        self.order_direction = direction;
+        self
+    }
+
+    /// One of [`PER_PAGE_OPTIONS`]; checked by [`validate`](Self::validate).
+    pub fn per_page(mut self, per_page: u32) -> Self {
+        self.per_page = per_page;
+        self
+    }
+
+    pub fn page(mut self, page: u32) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Check the parameters the server would reject, without sending a
+    /// request.  `list` does this automatically.
+    pub fn validate(&self) -> Result<()> {
+        validate_pagination(self.per_page, self.page)
+    }
+
+    /// Query string pairs for `GET /v1/project/{slug}/versions`.
+    pub(crate) fn to_query(&self) -> Vec<(String, String)> {
+        let mut query: Vec<(String, String)> = Vec::new();
+        if let Some(ref v) = self.search {
+            query.push(("search".into(), v.clone()));
+        }
+        if let Some(ref tags) = self.tags {
+            for t in tags {
+                query.push(("tags[]".into(), t.clone()));
+            }
+        }
+        query.push(("order_by".into(), self.order_by.to_string()));
+        query.push(("order_direction".into(), self.order_direction.to_string()));
+        query.push(("per_page".into(), self.per_page.to_string()));
+        query.push(("page".into(), self.page.to_string()));
+        query
+    }
+}
+
+/// Parameters for creating a new project version.
+pub struct CreateVersionParams {
+    pub name: String,
+    pub version: String,
+    pub release_type: String,
+    pub release_date: String,
+    pub changelog: String,
+    pub tags: Option<Vec<String>>,
+    pub dependencies: Option<Vec<Dependency>>,
+}
+
+impl CreateVersionParams {
+    /// Multipart form fields for `POST /v1/project/{slug}/versions`.
+    pub(crate) fn to_fields(&self) -> Vec<(String, String)> {
+        let mut fields: Vec<(String, String)> = vec![
+            ("name".into(), self.name.clone()),
+            ("version".into(), self.version.clone()),
+            ("release_type".into(), self.release_type.clone()),
+            ("release_date".into(), self.release_date.clone()),
+            ("changelog".into(), self.changelog.clone()),
+        ];
+
+        if let Some(ref tags) = self.tags {
+            for t in tags {
+                fields.push(("tags[]".into(), t.clone()));
+            }
+        }
+
+        if let Some(ref deps) = self.dependencies {
+            push_dependency_fields(&mut fields, deps);
+        }
+        fields
+    }
+}
+
+/// Parameters for updating an existing project version.
+#[derive(Default)]
+pub struct UpdateVersionParams {
+    pub name: Option<String>,
+    pub version_new: Option<String>,
+    pub release_type: Option<String>,
+    pub release_date: Option<String>,
+    pub changelog: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub files_to_remove: Option<Vec<String>>,
+    pub clean_existing_files: bool,
+    pub dependencies: Option<Vec<Dependency>>,
+}
+
+impl UpdateVersionParams {
+    /// Multipart form fields for `POST /v1/project/{slug}/version/{version}`.
+    pub(crate) fn to_fields(&self, version: &VersionSlug) -> Vec<(String, String)> {
+        // The API requires `version` field in the body.
+        let version_value = self.version_new.as_deref().unwrap_or(version);
+        let mut fields: Vec<(String, String)> = vec![("version".into(), version_value.to_string())];
+
+        if let Some(ref v) = self.name {
+            fields.push(("name".into(), v.clone()));
+        }
+        if let Some(ref v) = self.release_type {
+            fields.push(("release_type".into(), v.clone()));
+        }
+        if let Some(ref v) = self.release_date {
+            fields.push(("release_date".into(), v.clone()));
+        }
+        if let Some(ref v) = self.changelog {
+            fields.push(("changelog".into(), v.clone()));
+        }
+        if self.clean_existing_files {
+            fields.push(("clean_existing_files".into(), "1".into()));
+        }
+
+        if let Some(ref tags) = self.tags {
+            for t in tags {
+                fields.push(("tags[]".into(), t.clone()));
+            }
+        }
+
+        if let Some(ref deps) = self.dependencies {
+            push_dependency_fields(&mut fields, deps);
+        }
+
+        if let Some(ref removals) = self.files_to_remove {
+            for f in removals {
+                fields.push(("files_to_remove[]".into(), f.clone()));
+            }
+        }
+        fields
+    }
+}
+
+/// A dependency descriptor used when creating/updating versions.
+///
+/// `name` and `url` are only meaningful for external dependencies and are
+/// omitted from the request when `None`.
+pub struct Dependency {
+    pub project: String,
+    pub version: String,
+    pub dep_type: DependencyKind,
+    pub external: bool,
+    pub name: Option<String>,
+    pub url: Option<String>,
+}
+
+/// Append the `dependencies[i][...]` form fields for `deps`.
+fn push_dependency_fields(fields: &mut Vec<(String, String)>, deps: &[Dependency]) {
+    for (i, dep) in deps.iter().enumerate() {
+        fields.push((format!("dependencies[{i}][project]"), dep.project.clone()));
+        fields.push((format!("dependencies[{i}][version]"), dep.version.clone()));
+        fields.push((format!("dependencies[{i}][type]"), dep.dep_type.to_string()));
+        fields.push((
+            format!("dependencies[{i}][external]"),
+            if dep.external { "1" } else { "0" }.to_string(),
+        ));
+        if let Some(ref v) = dep.name {
+            fields.push((format!("dependencies[{i}][name]"), v.clone()));
+        }
+        if let Some(ref v) = dep.url {
+            fields.push((format!("dependencies[{i}][url]"), v.clone()));
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Convenience re-exports for glob import.
+//!
+//! ```no_run
+//! use hub01_client::prelude::*;
+//!
+//! # #[cfg(feature = "http")]
+//! fn main() -> Result<()> {
+//!     let client = HubClient::new("https://hub01-shop.srgnis.com/api", None)?;
+//!     let projects = client.projects().list(&ListProjectsParams::default())?;
+//!     for p in &projects.data {
+//!         println!("{} ({})", p.name, p.slug);
+//!     }
+//!     Ok(())
+//! }
+//! # #[cfg(not(feature = "http"))]
+//! # fn main() {}
+//! ```
+//!
+//! Note that this brings the crate's [`Result`] alias into scope, shadowing
+//! `std::result::Result` for the importing module.
+
+pub use crate::archive::ArchiveEntry;
+#[cfg(feature = "http")]
+pub use crate::client::{HubClient, ProjectHandle, VersionHandle};
+pub use crate::error::{HubApiError, Result};
+pub use crate::models::{
+    Capabilities, DependencyKind, PaginatedResponse, Project, ProjectFile, ProjectTag, ProjectType,
+    ProjectVersion, ProjectVersionDependency, ProjectVersionTag, User, VersionChannel,
+};
+pub use crate::params::{
+    CreateVersionParams, Dependency, ListProjectsParams, ListProjectsParamsBuilder,
+    ListVersionsParams, ProjectOrderBy, ReleaseDatePeriod, SortDirection, UpdateVersionParams,
+    VersionOrderBy,
+};
+pub use crate::slug::{ProjectSlug, TagSlug, Username, VersionSlug};
+pub use crate::tags::TagResolver;
+#[cfg(feature = "http")]
+pub use crate::transport::FileSource;
@@ -0,0 +1,139 @@
+//! Transfer progress with rolling speed and ETA estimates.
+//!
+//! ```
+//! use std::io::Write;
+//! use hub01_client::progress::ProgressWriter;
+//!
+//! let mut last = None;
+//! let mut out = ProgressWriter::new(Vec::new(), Some(5), |p| last = Some(*p));
+//! out.write_all(b"hello").unwrap();
+//! out.finish();
+//! drop(out);
+//! assert_eq!(last.unwrap().transferred, 5);
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Minimum time between two progress callbacks.
+pub const REPORT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How far back the transfer speed is averaged over, so it follows changes
+/// in bandwidth instead of averaging the whole transfer.
+pub const SPEED_WINDOW: Duration = Duration::from_secs(5);
+
+/// A snapshot of a transfer, passed to progress callbacks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Bytes transferred so far.
+    pub transferred: u64,
+    /// Expected size, when known.
+    pub total: Option<u64>,
+    /// Transfer speed over the last [`SPEED_WINDOW`], in bytes per second.
+    pub bytes_per_sec: f64,
+    /// Estimated time left; `None` while the size or speed is unknown.
+    pub eta: Option<Duration>,
+}
+
+impl Progress {
+    /// Completed fraction in `0.0..=1.0`, when the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total
+            .filter(|&total| total > 0)
+            .map(|total| (self.transferred as f64 / total as f64).min(1.0))
+    }
+}
+
+/// Passes writes through to `inner` and reports progress to `on_progress`
+/// at most every [`REPORT_INTERVAL`].  Call [`finish`](Self::finish) once
+/// the transfer is complete to report the final state.
+pub struct ProgressWriter<W, F> {
+    inner: W,
+    on_progress: F,
+    total: Option<u64>,
+    transferred: u64,
+    /// `(time, transferred)` samples within the speed window, oldest first.
+    samples: VecDeque<(Instant, u64)>,
+    last_report: Option<Instant>,
+}
+
+impl<W: Write, F: FnMut(&Progress)> ProgressWriter<W, F> {
+    pub fn new(inner: W, total: Option<u64>, on_progress: F) -> Self {
+        let mut samples = VecDeque::new();
+        samples.push_back((Instant::now(), 0));
+        Self {
+            inner,
+            on_progress,
+            total,
+            transferred: 0,
+            samples,
+            last_report: None,
+        }
+    }
+
+    /// The current state of the transfer.
+    pub fn progress(&self) -> Progress {
+        let bytes_per_sec = match (self.samples.front(), self.samples.back()) {
+            (Some(&(start, from)), Some(&(end, to))) if end > start => {
+                (to - from) as f64 / (end - start).as_secs_f64()
+            }
+            _ => 0.0,
+        };
+        let eta = self.total.and_then(|total| {
+            let left = total.saturating_sub(self.transferred);
+            if left == 0 {
+                Some(Duration::ZERO)
+            } else if bytes_per_sec > 0.0 {
+                Some(Duration::from_secs_f64(left as f64 / bytes_per_sec))
+            } else {
+                None
+            }
+        });
+        Progress {
+            transferred: self.transferred,
+            total: self.total,
+            bytes_per_sec,
+            eta,
+        }
+    }
+
+    /// Report the final state, regardless of [`REPORT_INTERVAL`].
+    pub fn finish(&mut self) {
+        let progress = self.progress();
+        (self.on_progress)(&progress);
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn record(&mut self, now: Instant) {
+        self.samples.push_back((now, self.transferred));
+        // Keep one sample older than the window so the speed covers all of it.
+        while self.samples.len() > 2 && now - self.samples[1].0 >= SPEED_WINDOW {
+            self.samples.pop_front();
+        }
+        if self
+            .last_report
+            .is_none_or(|last| now - last >= REPORT_INTERVAL)
+        {
+            self.last_report = Some(now);
+            let progress = self.progress();
+            (self.on_progress)(&progress);
+        }
+    }
+}
+
+impl<W: Write, F: FnMut(&Progress)> Write for ProgressWriter<W, F> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.transferred += n as u64;
+        self.record(Instant::now());
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
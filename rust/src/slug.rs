@@ -0,0 +1,151 @@
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::error::{HubApiError, Result};
+
+// ---------------------------------------------------------------------------
+// Typed identifiers used in endpoint paths
+// ---------------------------------------------------------------------------
+
+/// Reject values that cannot be used as a single URL path segment.
+fn validate(value: &str, what: &str) -> Result<()> {
+    let problem = if value.is_empty() {
+        Some("must not be empty")
+    } else if value
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '/' | '?' | '#' | '%'))
+    {
+        Some("must not contain whitespace, '/', '?', '#' or '%'")
+    } else if value.chars().all(|c| c == '.') {
+        // `.` and `..` would be resolved away as dot-segments of the URL.
+        Some("must not consist only of dots")
+    } else {
+        None
+    };
+
+    match problem {
+        None => Ok(()),
+        Some(problem) => Err(HubApiError::Validation {
+            message: format!("Invalid {what} '{value}': {problem}"),
+            errors: None,
+            response: None,
+        }),
+    }
+}
+
+macro_rules! slug_type {
+    ($(#[$meta:meta])* $name:ident, $what:literal) => {
+        $(#[$meta])*
+        ///
+        /// Construct with [`new`](Self::new) or `str::parse`; values coming
+        /// from API responses are trusted as-is.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Validate and wrap `value`.
+            pub fn new(value: impl Into<String>) -> Result<Self> {
+                let value = value.into();
+                validate(&value, $what)?;
+                Ok(Self(value))
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = HubApiError;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = HubApiError;
+
+            fn try_from(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = HubApiError;
+
+            fn try_from(s: String) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                String::deserialize(deserializer).map(Self)
+            }
+        }
+    };
+}
+
+slug_type!(
+    /// Slug identifying a project, e.g. `my-mod`.
+    ProjectSlug,
+    "project slug"
+);
+
+slug_type!(
+    /// Version string identifying a version within a project, e.g. `1.2.3`.
+    VersionSlug,
+    "version"
+);
+
+slug_type!(
+    /// Slug identifying a project or version tag.
+    TagSlug,
+    "tag slug"
+);
+
+slug_type!(
+    /// Name identifying a user profile.
+    Username,
+    "username"
+);
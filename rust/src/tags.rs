@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use crate::models::ProjectTag;
+
+// ---------------------------------------------------------------------------
+// Tag slug → display name resolution
+// ---------------------------------------------------------------------------
+
+/// Separator between a main tag and its sub-tag in
+/// [`TagResolver::display_path`].
+pub const TAG_PATH_SEPARATOR: &str = " > ";
+
+/// Lookup table from tag slugs to the tags of one project type, so tag slugs
+/// found on projects can be shown with their human-readable names.
+///
+/// Load it once with
+/// [`TagsClient::resolver`](crate::TagsClient::resolver) (or build it from
+/// an already fetched listing with [`new`](Self::new)) and reuse it for every
+/// row that needs rendering.
+///
+/// ```
+/// use hub01_client::{ProjectTag, TagResolver};
+///
+/// let tags: Vec<ProjectTag> = serde_json::from_str(r#"[{
+///     "name": "Gameplay", "slug": "gameplay", "icon": "", "tag_group": null,
+///     "project_types": ["mod"], "main_tag": null,
+///     "sub_tags": [{
+///         "name": "Combat", "slug": "combat", "icon": "", "tag_group": null,
+///         "project_types": ["mod"], "main_tag": "gameplay"
+///     }]
+/// }]"#).unwrap();
+///
+/// let resolver = TagResolver::new(tags);
+/// assert_eq!(resolver.resolve("combat").unwrap().name, "Combat");
+/// assert_eq!(resolver.display_path("combat"), "Gameplay > Combat");
+/// assert_eq!(resolver.display_path("unknown"), "unknown");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TagResolver {
+    tags: Vec<ProjectTag>,
+    /// Index into `tags` and of the tag's parent, by slug.
+    index: HashMap<String, (usize, Option<usize>)>,
+}
+
+impl TagResolver {
+    /// Build a resolver from a tag listing, nested (sub-tags inside their
+    /// main tag) or plain (sub-tags naming their `main_tag`).
+    pub fn new(tags: Vec<ProjectTag>) -> Self {
+        let mut resolver = Self::default();
+        for tag in tags {
+            resolver.insert(tag, None);
+        }
+
+        // Plain listings only link sub-tags to their parent via `main_tag`,
+        // which may hold the parent's slug or its name.
+        let by_name: HashMap<&str, usize> = resolver
+            .tags
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.name.as_str(), i))
+            .collect();
+        let mut links = Vec::new();
+        for (slug, &(i, parent)) in &resolver.index {
+            let Some(ref main) = resolver.tags[i].main_tag else {
+                continue;
+            };
+            if parent.is_none() {
+                let found = resolver
+                    .index
+                    .get(main.as_str())
+                    .map(|&(p, _)| p)
+                    .or_else(|| by_name.get(main.as_str()).copied());
+                if let Some(p) = found.filter(|&p| p != i) {
+                    links.push((slug.clone(), p));
+                }
+            }
+        }
+        for (slug, p) in links {
+            if let Some(entry) = resolver.index.get_mut(&slug) {
+                entry.1 = Some(p);
+            }
+        }
+        resolver
+    }
+
+    fn insert(&mut self, mut tag: ProjectTag, parent: Option<usize>) {
+        let i = self.tags.len();
+        let subs = std::mem::take(&mut tag.sub_tags);
+        self.index.insert(tag.slug.to_string(), (i, parent));
+        self.tags.push(tag);
+        for sub in subs {
+            self.insert(sub, Some(i));
+        }
+    }
+
+    /// The tag with `slug`, including sub-tags.  The returned tag's
+    /// `sub_tags` are empty; sub-tags are resolved on their own.
+    pub fn resolve(&self, slug: &str) -> Option<&ProjectTag> {
+        self.index.get(slug).map(|&(i, _)| &self.tags[i])
+    }
+
+    /// The display name of `slug` prefixed by those of its parent tags, e.g.
+    /// `"Gameplay > Combat"`.  Unknown slugs are returned unchanged so
+    /// callers can render every tag the same way.
+    pub fn display_path(&self, slug: &str) -> String {
+        let Some(&(i, mut parent)) = self.index.get(slug) else {
+            return slug.to_string();
+        };
+        let mut names = vec![self.tags[i].name.as_str()];
+        // Bounded by the tag count in case `main_tag` links form a cycle.
+        while let Some(p) = parent.filter(|_| names.len() <= self.tags.len()) {
+            names.push(&self.tags[p].name);
+            parent = self.index[self.tags[p].slug.as_str()].1;
+        }
+        names.reverse();
+        names.join(TAG_PATH_SEPARATOR)
+    }
+
+    /// Number of known tags, sub-tags included.
+    pub fn len(&self) -> usize {
+        self.tags.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+}
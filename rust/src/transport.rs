@@ -0,0 +1,370 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::blocking::{Body, Client};
+
+use crate::error::{HubApiError, Result};
+
+// ---------------------------------------------------------------------------
+// Pluggable HTTP transport
+// ---------------------------------------------------------------------------
+
+/// A fully prepared HTTP request handed to a [`Transport`].
+///
+/// The URL already carries the query string, and `headers` include the
+/// `Accept` and `Authorization` headers configured on the client.  The
+/// `Debug` output shows the `Authorization` value as `<redacted>`.
+#[derive(Clone)]
+pub struct HttpRequest {
+    /// `GET`, `POST` or `DELETE`.
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<RequestBody>,
+}
+
+impl HttpRequest {
+    /// Value of the first header named `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// Body of an [`HttpRequest`].  Uploads are streamed from their
+/// [`FileSource`] when the body is read rather than held in memory.
+#[derive(Clone)]
+pub struct RequestBody {
+    segments: Arc<[Segment]>,
+    len: Option<u64>,
+}
+
+enum Segment {
+    Bytes(Arc<[u8]>),
+    File(PathBuf),
+    /// Taken on first read; readers cannot be rewound.
+    Reader(Mutex<Option<Box<dyn Read + Send>>>),
+}
+
+impl RequestBody {
+    /// Length in bytes, if known up front (it is not when a
+    /// [`FileSource::Reader`] is part of the body).
+    pub fn len(&self) -> Option<u64> {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == Some(0)
+    }
+
+    /// Open a stream over the body.  Files are reopened on every call;
+    /// a body containing a [`FileSource::Reader`] can only be read once.
+    pub fn reader(&self) -> io::Result<Box<dyn Read + Send>> {
+        let mut body: Box<dyn Read + Send> = Box::new(io::empty());
+        for segment in self.segments.iter() {
+            let next: Box<dyn Read + Send> = match segment {
+                Segment::Bytes(bytes) => Box::new(Cursor::new(bytes.clone())),
+                Segment::File(path) => Box::new(File::open(path)?),
+                Segment::Reader(reader) => reader
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .take()
+                    .ok_or_else(|| io::Error::other("upload stream was already consumed"))?,
+            };
+            body = Box::new(body.chain(next));
+        }
+        Ok(body)
+    }
+
+    /// Read the whole body into memory.
+    pub fn to_vec(&self) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.reader()?.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+impl From<Vec<u8>> for RequestBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self {
+            len: Some(bytes.len() as u64),
+            segments: Arc::new([Segment::Bytes(bytes.into())]),
+        }
+    }
+}
+
+impl fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let headers: Vec<(&str, &str)> = self
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                if name.eq_ignore_ascii_case("authorization") {
+                    (name.as_str(), "<redacted>")
+                } else {
+                    (name.as_str(), value.as_str())
+                }
+            })
+            .collect();
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &headers)
+            .field("body", &self.body)
+            .finish()
+    }
+}
+
+impl fmt::Debug for RequestBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RequestBody")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Contents of a file to upload with
+/// [`ProjectVersionsClient::create`](crate::ProjectVersionsClient::create)
+/// or [`update`](crate::ProjectVersionsClient::update).
+///
+/// Paths and readers are streamed into the request, so large archives are
+/// never loaded into memory whole.
+///
+/// ```no_run
+/// use hub01_client::FileSource;
+///
+/// let from_disk = FileSource::path("build/libs/my-mod-1.0.jar");
+/// let in_memory = FileSource::from(b"PK".to_vec());
+/// let from_stdin = FileSource::reader(std::io::stdin());
+/// ```
+pub enum FileSource {
+    /// A file on disk, read while the request is sent.
+    Path(PathBuf),
+    /// Any reader.  Its length is unknown, so the request is sent with
+    /// chunked transfer encoding, and it cannot be resent on a retry.
+    Reader(Box<dyn Read + Send>),
+    /// Bytes already in memory.
+    Bytes(Vec<u8>),
+}
+
+impl FileSource {
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Self::Path(path.into())
+    }
+
+    pub fn reader(reader: impl Read + Send + 'static) -> Self {
+        Self::Reader(Box::new(reader))
+    }
+}
+
+impl From<Vec<u8>> for FileSource {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<PathBuf> for FileSource {
+    fn from(path: PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl From<&Path> for FileSource {
+    fn from(path: &Path) -> Self {
+        Self::Path(path.to_path_buf())
+    }
+}
+
+impl fmt::Debug for FileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Reader(_) => f.write_str("Reader(..)"),
+            Self::Bytes(bytes) => write!(f, "Bytes({} bytes)", bytes.len()),
+        }
+    }
+}
+
+/// Response returned by a [`Transport`]; the body is streamed so large
+/// downloads need not be buffered.
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Box<dyn Read + Send>,
+}
+
+impl HttpResponse {
+    /// Value of the first header named `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+impl std::fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Sends HTTP requests on behalf of [`HubClient`](crate::HubClient).
+///
+/// Implement this to use another HTTP library or to answer requests
+/// in-process in tests, then pass it to
+/// [`HubClient::with_transport`](crate::HubClient::with_transport).  Any
+/// status code must be returned as a response; only failures to get a
+/// response at all are errors, preferably as [`HubApiError::Io`] so the
+/// circuit breaker counts them.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse>;
+}
+
+/// The default [`Transport`], backed by a blocking `reqwest` client.
+pub struct ReqwestTransport {
+    http: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Result<Self> {
+        Ok(Self::from_client(Client::builder().build()?))
+    }
+
+    /// Use an already configured `reqwest` client (timeouts, proxies, ...).
+    pub fn from_client(http: Client) -> Self {
+        Self { http }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> Result<HttpResponse> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|e| {
+            HubApiError::Api {
+                status: 0,
+                message: format!("Invalid HTTP method: {e}"),
+                response: None,
+            }
+        })?;
+        let mut builder = self.http.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            let reader = body.reader()?;
+            builder = builder.body(match body.len() {
+                Some(len) => Body::sized(reader, len),
+                None => Body::new(reader),
+            });
+        }
+
+        let response = builder.send()?;
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(k, v)| {
+                (
+                    k.to_string(),
+                    String::from_utf8_lossy(v.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            headers,
+            body: Box::new(response),
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// multipart/form-data encoding
+// ---------------------------------------------------------------------------
+
+/// Encode text `fields` and `files` (sent as `files[]` parts) as a
+/// `multipart/form-data` body, returning the `Content-Type` and the body.
+/// File contents are not read here; only the sizes of paths are looked up.
+pub(crate) fn multipart_body(
+    fields: &[(String, String)],
+    files: Vec<(&str, FileSource)>,
+) -> io::Result<(String, RequestBody)> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let boundary = format!(
+        "hub01-{nanos:x}-{:x}",
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let mut segments = Vec::new();
+    let mut len = Some(0u64);
+    let mut buf = Vec::new();
+    for (name, value) in fields {
+        buf.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n",
+                quote(name)
+            )
+            .as_bytes(),
+        );
+        buf.extend_from_slice(value.as_bytes());
+        buf.extend_from_slice(b"\r\n");
+    }
+    for (filename, source) in files {
+        buf.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"files[]\"; filename=\"{}\"\r\nContent-Type: application/octet-stream\r\n\r\n",
+                quote(filename)
+            )
+            .as_bytes(),
+        );
+        match source {
+            FileSource::Bytes(bytes) => buf.extend_from_slice(&bytes),
+            FileSource::Path(path) => {
+                let size = std::fs::metadata(&path)?.len();
+                len = len.map(|n| n + buf.len() as u64 + size);
+                segments.push(Segment::Bytes(std::mem::take(&mut buf).into()));
+                segments.push(Segment::File(path));
+            }
+            FileSource::Reader(reader) => {
+                len = None;
+                segments.push(Segment::Bytes(std::mem::take(&mut buf).into()));
+                segments.push(Segment::Reader(Mutex::new(Some(reader))));
+            }
+        }
+        buf.extend_from_slice(b"\r\n");
+    }
+    buf.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    len = len.map(|n| n + buf.len() as u64);
+    segments.push(Segment::Bytes(buf.into()));
+
+    Ok((
+        format!("multipart/form-data; boundary={boundary}"),
+        RequestBody {
+            segments: segments.into(),
+            len,
+        },
+    ))
+}
+
+/// Percent-encode the characters that would break a quoted header parameter,
+/// as browsers do for form field and file names.
+fn quote(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
@@ -0,0 +1,158 @@
+//! Humane ordering of version strings, for sorting version listings
+//! client-side and picking a "latest" version when the server order is not
+//! enough (e.g. several versions released on the same day).
+//!
+//! ```
+//! use hub01_client::versions;
+//!
+//! let mut list = vec!["1.10", "1.2-rc1", "v1.2", "1.2-beta.2", "1.9.1"];
+//! list.sort_by(|a, b| versions::compare(a, b));
+//! assert_eq!(list, ["1.2-beta.2", "1.2-rc1", "v1.2", "1.9.1", "1.10"]);
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::models::ProjectVersion;
+
+/// Compare two version strings.
+///
+/// - A leading `v`/`V` and any `+build` metadata are ignored.
+/// - The dotted numeric core is compared segment by segment as numbers,
+///   with missing segments counting as zero (`1.2` and `1.2.0` rank equal).
+/// - Anything after the core (`-rc1`, `.beta.2`, `alpha`) is a pre-release
+///   and ranks below the plain version.  Pre-releases are compared in runs of
+///   digits (numerically) and letters (case-insensitively), so
+///   `alpha < beta < rc` and `rc2 < rc10`.
+///
+/// Versions that rank equal are finally ordered by their raw text, so the
+/// result is a total order and sorting is deterministic.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    let (a_core, a_pre) = split(a);
+    let (b_core, b_pre) = split(b);
+    compare_core(a_core, b_core)
+        .then_with(|| match (a_pre.is_empty(), b_pre.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => compare_pre_release(a_pre, b_pre),
+        })
+        .then_with(|| a.cmp(b))
+}
+
+/// Sort versions newest first by their version string.
+pub fn sort_newest_first(versions: &mut [ProjectVersion]) {
+    versions.sort_by(|a, b| compare(&b.version, &a.version));
+}
+
+/// The version with the highest version string.
+pub fn latest<'a, I>(versions: I) -> Option<&'a ProjectVersion>
+where
+    I: IntoIterator<Item = &'a ProjectVersion>,
+{
+    versions
+        .into_iter()
+        .max_by(|a, b| compare(&a.version, &b.version))
+}
+
+/// Index of the newest version of `channel` in a page ordered by release
+/// date (newest first), breaking same-day ties by version string.
+#[cfg(feature = "http")]
+pub(crate) fn newest_released(
+    versions: &[ProjectVersion],
+    channel: crate::models::VersionChannel,
+) -> Option<usize> {
+    let first = versions.iter().position(|v| channel.includes(v))?;
+    let date = &versions[first].release_date;
+    versions
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take_while(|(_, v)| v.release_date == *date)
+        .filter(|(_, v)| channel.includes(v))
+        .max_by(|(_, a), (_, b)| compare(&a.version, &b.version))
+        .map(|(i, _)| i)
+}
+
+/// Split a version into its numeric core and pre-release suffix.
+fn split(version: &str) -> (&str, &str) {
+    let version = version.trim();
+    let version = version.strip_prefix(['v', 'V']).unwrap_or(version);
+    let version = version.split('+').next().unwrap_or(version);
+    let end = version
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(version.len());
+    let (core, pre) = version.split_at(end);
+    (
+        core.trim_end_matches('.'),
+        pre.trim_start_matches(['-', '.', '_']),
+    )
+}
+
+fn compare_core(a: &str, b: &str) -> Ordering {
+    let mut a = a.split('.').filter(|s| !s.is_empty());
+    let mut b = b.split('.').filter(|s| !s.is_empty());
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (x, y) => {
+                let ordering = compare_numbers(x.unwrap_or("0"), y.unwrap_or("0"));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+fn compare_pre_release(a: &str, b: &str) -> Ordering {
+    let mut a = runs(a);
+    let mut b = runs(b);
+    loop {
+        return match (a.next(), b.next()) {
+            (None, None) => Ordering::Equal,
+            // Fewer identifiers rank lower, as in semver: `rc < rc.1`.
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let x_numeric = x.starts_with(|c: char| c.is_ascii_digit());
+                let y_numeric = y.starts_with(|c: char| c.is_ascii_digit());
+                let ordering = match (x_numeric, y_numeric) {
+                    (true, true) => compare_numbers(x, y),
+                    // Numbers rank below words, as in semver.
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => x.to_ascii_lowercase().cmp(&y.to_ascii_lowercase()),
+                };
+                if ordering == Ordering::Equal {
+                    continue;
+                }
+                ordering
+            }
+        };
+    }
+}
+
+/// Compare two digit strings by value, without overflowing on long ones.
+fn compare_numbers(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Split a pre-release into runs of digits and runs of letters, dropping
+/// separators and other punctuation.
+fn runs(s: &str) -> impl Iterator<Item = &str> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        rest = rest.trim_start_matches(|c: char| !c.is_ascii_alphanumeric());
+        let first = rest.chars().next()?;
+        let end = rest
+            .find(|c: char| {
+                !c.is_ascii_alphanumeric() || c.is_ascii_digit() != first.is_ascii_digit()
+            })
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+        Some(run)
+    })
+}
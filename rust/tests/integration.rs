@@ -10,208 +10,1956 @@
 //! cargo test -- --nocapture
 //! ```
 
-use hub01_client::{HubClient, ListProjectsParams, ListVersionsParams};
+#![cfg(feature = "http")]
+
+use hub01_client::{
+    archive, badge, progress, CachePolicy, CreateVersionParams, DependencyKind, ErrorKind,
+    FileSource, HttpRequest, HttpResponse, HubApiError, HubClient, ListProjectsParams,
+    ListVersionsParams, ProjectFile, ProjectOrderBy, ProjectSlug, ProjectTag, RetryPolicy,
+    SortDirection, TagResolver, Transport, Username, VersionChannel, VersionOrderBy, VersionSlug,
+};
 use std::fs;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 fn read_credential_file(name: &str) -> Option<String> {
     fs::read_to_string(name).ok().map(|s| s.trim().to_string())
 }
 
-fn create_dummy_file(content: &str) -> (String, Vec<u8>) {
-    ("test_file.txt".to_string(), content.as_bytes().to_vec())
-}
+fn create_dummy_file(content: &str) -> (String, Vec<u8>) {
+    ("test_file.txt".to_string(), content.as_bytes().to_vec())
+}
+
+fn base_url() -> String {
+    std::env::var("HUB01_BASE_URL").unwrap_or_else(|_| "https://hub01-shop.srgnis.com/api".into())
+}
+
+fn credentials() -> (Option<String>, Option<String>) {
+    let username = std::env::var("HUB01_USERNAME")
+        .ok()
+        .or_else(|| read_credential_file("username"));
+    let token = std::env::var("HUB01_TOKEN")
+        .ok()
+        .or_else(|| read_credential_file("api_key"));
+    (username, token)
+}
+
+// ---------------------------------------------------------------------------
+// Offline fixtures
+// ---------------------------------------------------------------------------
+
+/// A canned response from a [`ScriptedTransport`].
+struct Reply {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl Reply {
+    fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Reply {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A 200 JSON response.
+    fn json(body: impl Into<Vec<u8>>) -> Self {
+        Reply::new(200, body).header("Content-Type", "application/json")
+    }
+
+    fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+type Script = dyn Fn(&HttpRequest, usize) -> Reply + Send + Sync;
+
+/// Fake server for the offline tests: records every request and answers it
+/// with `script(request, index)`, `index` counting from 0.  Clones share the
+/// record, so keep one to inspect what the client sent.
+#[derive(Clone)]
+struct ScriptedTransport {
+    sent: Arc<Mutex<Vec<HttpRequest>>>,
+    script: Arc<Script>,
+}
+
+impl ScriptedTransport {
+    fn new(script: impl Fn(&HttpRequest, usize) -> Reply + Send + Sync + 'static) -> Self {
+        ScriptedTransport {
+            sent: Arc::default(),
+            script: Arc::new(script),
+        }
+    }
+
+    /// Answers every request with a 200 and `body`.
+    fn fixed(body: &'static str) -> Self {
+        Self::new(move |_, _| Reply::json(body))
+    }
+
+    /// Answers the first `failures` requests with `status` (and a zero
+    /// `Retry-After`), then with an empty list.
+    fn flaky(failures: usize, status: u16) -> Self {
+        Self::new(move |_, i| {
+            if i < failures {
+                Reply::new(status, r#"{"message": "Try again"}"#)
+            } else {
+                Reply::json(r#"{"data": []}"#)
+            }
+            .header("Retry-After", "0")
+        })
+    }
+
+    /// Serves `last_page` pages of two projects each, numbered by the `page`
+    /// query parameter.
+    fn paged(last_page: u32) -> Self {
+        Self::new(move |request, _| {
+            let page: u32 = query_param(request, "page").unwrap().parse().unwrap();
+            let body = serde_json::json!({
+                "data": [fake_project(&format!("p{page}a")), fake_project(&format!("p{page}b"))],
+                "meta": { "current_page": page, "last_page": last_page },
+            });
+            Reply::json(body.to_string())
+        })
+    }
+
+    fn client(&self, token: Option<&str>) -> HubClient {
+        HubClient::with_transport("http://hub.invalid/api", token, self.clone()).unwrap()
+    }
+
+    fn sent(&self) -> std::sync::MutexGuard<'_, Vec<HttpRequest>> {
+        self.sent.lock().unwrap()
+    }
+
+    fn calls(&self) -> usize {
+        self.sent().len()
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn send(&self, request: HttpRequest) -> hub01_client::error::Result<HttpResponse> {
+        let reply = {
+            let mut sent = self.sent.lock().unwrap();
+            let reply = (self.script)(&request, sent.len());
+            sent.push(request);
+            reply
+        };
+        Ok(HttpResponse {
+            status: reply.status,
+            headers: reply.headers,
+            body: Box::new(std::io::Cursor::new(reply.body)),
+        })
+    }
+}
+
+/// Value of the first `name` parameter in the request's query string.
+fn query_param<'r>(request: &'r HttpRequest, name: &str) -> Option<&'r str> {
+    let (_, query) = request.url.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|kv| kv.strip_prefix(name)?.strip_prefix('='))
+}
+
+fn fake_project(slug: &str) -> serde_json::Value {
+    serde_json::json!({
+        "name": slug, "slug": slug, "summary": "", "description": null,
+        "logo_url": "", "website": null, "issues": null, "source": null,
+        "status": "active", "downloads": 0, "created_at": "2025-01-01T00:00:00.000000Z",
+        "last_release_date": null,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// 1. Project types
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_project_types() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let types = client.project_types().list().unwrap();
+    assert!(!types.is_empty(), "Expected at least one project type");
+    println!("[1] Found {} project types", types.len());
+    for t in types.iter().take(3) {
+        println!("  - {} ({})", t.name, t.slug);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 1b. Instance capabilities
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_capabilities() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let caps = client.capabilities().unwrap();
+    assert!(!caps.authenticated);
+    println!("[1b] Capabilities: {caps:?}");
+}
+
+// ---------------------------------------------------------------------------
+// 2. Project tags
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_project_tags() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let tags = client.tags().list_project_tags(true, None).unwrap();
+    assert!(!tags.is_empty(), "Expected at least one project tag");
+    println!("[2] Found {} project tags", tags.len());
+    println!("  - First tag: {} ({})", tags[0].name, tags[0].slug);
+
+    // Fetch a specific tag
+    let detail = client.tags().get_project_tag(&tags[0].slug).unwrap();
+    println!("  - Tag icon: {}", detail.icon);
+}
+
+// ---------------------------------------------------------------------------
+// 3. Version tags
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_version_tags() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let tags = client.tags().list_version_tags(true, None).unwrap();
+    assert!(!tags.is_empty(), "Expected at least one version tag");
+    println!("[3] Found {} version tags", tags.len());
+    println!("  - First tag: {}", tags[0].name);
+}
+
+// ---------------------------------------------------------------------------
+// 4. List projects
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_projects() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let resp = client
+        .projects()
+        .list(&ListProjectsParams {
+            per_page: 10,
+            ..Default::default()
+        })
+        .unwrap();
+    assert!(!resp.data.is_empty(), "Expected at least one project");
+    println!("[4] Found {} projects (page 1)", resp.data.len());
+
+    let total = client
+        .projects()
+        .count(&ListProjectsParams::default())
+        .unwrap();
+    assert!(total >= resp.data.len() as u64);
+    println!("[4] Total matching projects: {total}");
+    for p in resp.data.iter().take(3) {
+        println!("  - {} (downloads: {})", p.name, p.downloads);
+    }
+
+    // Follow pagination links
+    if let Some(page2) = resp.next_page(&client).unwrap() {
+        println!("[4] Found {} projects (page 2)", page2.data.len());
+        let back = page2.prev_page(&client).unwrap().unwrap();
+        assert_eq!(back.data.len(), resp.data.len());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 4b. Pagination parameter validation (no request is sent)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_invalid_pagination_rejected() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let err = client
+        .projects()
+        .list(&ListProjectsParams {
+            per_page: 7,
+            page: 0,
+            ..Default::default()
+        })
+        .unwrap_err();
+    match err {
+        hub01_client::HubApiError::Validation { errors, .. } => {
+            let errors = errors.unwrap();
+            assert!(errors.get("per_page").is_some());
+            assert!(errors.get("page").is_some());
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 5. Filter / search projects
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_filter_projects() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+
+    // First list to get a search term
+    let resp = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    if let Some(first) = resp.data.first() {
+        let term = first.name.split_whitespace().next().unwrap_or(&first.name);
+        let search = client
+            .projects()
+            .list(&ListProjectsParams {
+                search: Some(term.to_string()),
+                per_page: 10,
+                ..Default::default()
+            })
+            .unwrap();
+        println!(
+            "[5] Search for '{}' returned {} results",
+            term,
+            search.data.len()
+        );
+
+        // Order by name ASC
+        let filtered = client
+            .projects()
+            .list(&ListProjectsParams {
+                order_by: ProjectOrderBy::Name,
+                order_direction: SortDirection::Asc,
+                per_page: 10,
+                ..Default::default()
+            })
+            .unwrap();
+        println!(
+            "[5] Filtered by type 'mod', ordered by name: {} results",
+            filtered.data.len()
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 5b. Snapshot crawl
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_crawl_snapshot() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let oldest = client
+        .projects()
+        .list(&ListProjectsParams {
+            order_by: ProjectOrderBy::CreatedAt,
+            order_direction: SortDirection::Asc,
+            ..Default::default()
+        })
+        .unwrap();
+    let cutoff = oldest.data[0].created_at.clone();
+
+    let crawled = client
+        .projects()
+        .crawl_snapshot(&ListProjectsParams::default(), &cutoff)
+        .unwrap();
+    assert!(!crawled.is_empty());
+    assert!(crawled.iter().all(|p| p.created_at <= cutoff));
+    println!("[5b] Crawled {} projects up to {cutoff}", crawled.len());
+}
+
+// ---------------------------------------------------------------------------
+// 5c. Did-you-mean suggestions on 404
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_not_found_suggestions() {
+    let client = HubClient::new(&base_url(), None)
+        .unwrap()
+        .suggest_similar(true);
+    let resp = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    let Some(first) = resp.data.first() else {
+        return;
+    };
+
+    // Drop the last character to simulate a typo
+    let mut typo = first.slug.to_string();
+    typo.pop();
+    match client.projects().get(&typo.parse().unwrap()) {
+        Err(HubApiError::NotFound { suggestions, .. }) => {
+            assert!(suggestions.len() <= hub01_client::MAX_SUGGESTIONS);
+            println!("[5c] '{typo}' not found, suggestions: {suggestions:?}");
+        }
+        Ok(p) => println!("[5c] '{typo}' exists ({}), skipping", p.name),
+        Err(e) => panic!("unexpected error: {e}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 6. List versions of a project
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_versions() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let projects = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    let slug = &projects.data[0].slug;
+
+    let versions = client
+        .versions()
+        .list(slug, &ListVersionsParams::default())
+        .unwrap();
+    println!(
+        "[6] Project '{}' has {} versions",
+        slug,
+        versions.data.len()
+    );
+    for v in versions.data.iter().take(3) {
+        println!(
+            "  - {} ({}, downloads: {})",
+            v.version, v.release_type, v.downloads
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 7. Filter versions + get details
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_filter_versions_and_details() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let projects = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    let slug = &projects.data[0].slug;
+
+    let versions = client
+        .versions()
+        .list(
+            slug,
+            &ListVersionsParams::new()
+                .order_by(VersionOrderBy::ReleaseDate)
+                .order_direction(SortDirection::Desc),
+        )
+        .unwrap();
+    println!(
+        "[7] Filtered versions (by release_date desc): {} results",
+        versions.data.len()
+    );
+
+    if let Some(v) = versions.data.first() {
+        let detail = client.versions().get(slug, &v.version).unwrap();
+        println!("[7] Version details: {}", detail.version);
+        println!("  - Files: {}", detail.files.len());
+        println!("  - Dependencies: {}", detail.dependencies.len());
+
+        // Same lookup through entity handles
+        let via_handle = client
+            .project(slug)
+            .unwrap()
+            .version(&v.version)
+            .unwrap()
+            .get()
+            .unwrap();
+        assert_eq!(via_handle.version, detail.version);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 7b. Dependency validation
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_validate_dependencies() {
+    let client = HubClient::new(&base_url(), None).unwrap();
+    let projects = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    let slug = &projects.data[0].slug;
+
+    let deps = vec![
+        hub01_client::Dependency {
+            project: slug.to_string(),
+            version: String::new(),
+            dep_type: DependencyKind::Optional,
+            external: false,
+            name: None,
+            url: None,
+        },
+        hub01_client::Dependency {
+            project: "this-project-does-not-exist-hopefully".into(),
+            version: String::new(),
+            dep_type: DependencyKind::Optional,
+            external: false,
+            name: None,
+            url: None,
+        },
+        hub01_client::Dependency {
+            project: "some-external-thing".into(),
+            version: String::new(),
+            dep_type: DependencyKind::Optional,
+            external: true,
+            name: Some("Some External Thing".into()),
+            url: Some("https://example.com/some-external-thing".into()),
+        },
+    ];
+
+    match client.versions().validate_dependencies(&deps) {
+        Err(hub01_client::HubApiError::Validation { errors, .. }) => {
+            let errors = errors.unwrap();
+            assert!(errors.get("dependencies.1.project").is_some());
+            assert!(errors.get("dependencies.0.project").is_none());
+            println!("[7b] Validation errors: {errors}");
+        }
+        other => panic!("Expected validation error, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_dependency_kind_decoding() {
+    let dep = |kind: &str| {
+        serde_json::from_value::<hub01_client::ProjectVersionDependency>(serde_json::json!({
+            "project": "lib", "version": null, "type": kind, "external": false,
+        }))
+        .unwrap()
+        .dep_type
+    };
+    assert_eq!(dep("embedded"), DependencyKind::Embedded);
+    // Kinds added server-side later must not break decoding, and are sent
+    // back as they came.
+    let unknown = dep("recommended");
+    assert_eq!(unknown, DependencyKind::Unknown("recommended".into()));
+    assert_eq!(unknown.to_string(), "recommended");
+}
+
+// ---------------------------------------------------------------------------
+// 7c. Circuit breaker (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_circuit_breaker_opens() {
+    // Nothing listens on the discard port, so every call fails to connect.
+    let client = HubClient::new("http://127.0.0.1:9/api", None)
+        .unwrap()
+        .circuit_breaker(2, std::time::Duration::from_secs(60));
+
+    for _ in 0..2 {
+        assert!(matches!(
+            client.project_types().list(),
+            Err(hub01_client::HubApiError::RequestFailed(_))
+        ));
+    }
+    assert!(matches!(
+        client.project_types().list(),
+        Err(hub01_client::HubApiError::CircuitOpen { .. })
+    ));
+}
+
+#[test]
+fn test_circuit_breaker_half_open() {
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    // Two failures open the circuit; the first trial fails too, the second
+    // blocks until released and then succeeds.
+    let (started_tx, started_rx) = mpsc::channel();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let (started_tx, release_rx) = (Mutex::new(started_tx), Mutex::new(release_rx));
+    let fake = ScriptedTransport::new(move |_, i| match i {
+        0..=2 => Reply::new(500, r#"{"message": "Boom"}"#),
+        3 => {
+            started_tx.lock().unwrap().send(()).unwrap();
+            release_rx.lock().unwrap().recv().unwrap();
+            Reply::json(r#"{"data": []}"#)
+        }
+        _ => Reply::json(r#"{"data": []}"#),
+    });
+    let cooldown = Duration::from_millis(50);
+    let client = fake.client(None).circuit_breaker(2, cooldown);
+    let open = |r: hub01_client::error::Result<_>| {
+        matches!(r, Err(hub01_client::HubApiError::CircuitOpen { .. }))
+    };
+
+    for _ in 0..2 {
+        assert!(client.project_types().list().is_err());
+    }
+    assert!(open(client.project_types().list()));
+    assert_eq!(fake.calls(), 2);
+
+    // A failed trial reopens the circuit at once.
+    std::thread::sleep(cooldown);
+    assert!(!open(client.project_types().list()));
+    assert!(open(client.project_types().list()));
+    assert_eq!(fake.calls(), 3);
+
+    // While the next trial is in flight everyone else is still refused.
+    std::thread::sleep(cooldown);
+    std::thread::scope(|s| {
+        let trial = s.spawn(|| client.project_types().list());
+        started_rx.recv().unwrap();
+        assert!(open(client.project_types().list()));
+        release_tx.send(()).unwrap();
+        assert!(trial.join().unwrap().is_ok());
+    });
+    assert!(client.project_types().list().is_ok());
+    assert_eq!(fake.calls(), 5);
+}
+
+// ---------------------------------------------------------------------------
+// 7d. Request coalescing (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_coalesced_requests_all_complete() {
+    let client = HubClient::new("http://127.0.0.1:9/api", None)
+        .unwrap()
+        .coalesce_requests(true);
+
+    std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| s.spawn(|| client.project_types().list()))
+            .collect();
+        // Waiters get a copy of the leader's error of the same kind.
+        for h in handles {
+            let err = h.join().unwrap().unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Network);
+            assert!(err.is_retryable());
+        }
+    });
+}
+
+// ---------------------------------------------------------------------------
+// 7e. Read-only mode (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_read_only_blocks_mutations() {
+    // An unreachable server proves the refusal happens before any request.
+    let client = HubClient::new("http://127.0.0.1:9/api", Some("token"))
+        .unwrap()
+        .read_only(true);
+    let project: ProjectSlug = "some-project".parse().unwrap();
+    let version: VersionSlug = "1.0.0".parse().unwrap();
+    match client.versions().delete(&project, &version) {
+        Err(hub01_client::HubApiError::ReadOnly { method, endpoint }) => {
+            assert_eq!(method, "DELETE");
+            assert_eq!(endpoint, "/v1/project/some-project/version/1.0.0");
+        }
+        other => panic!("Expected read-only error, got {other:?}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// 7f. Audit hook (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_audit_hook_sees_mutations() {
+    use std::sync::{Arc, Mutex};
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = events.clone();
+    let client = HubClient::new("http://127.0.0.1:9/api", Some("token"))
+        .unwrap()
+        .on_mutation(move |e| sink.lock().unwrap().push(e.clone()));
+
+    let _ = client.project_types().list();
+    let project: ProjectSlug = "some-project".parse().unwrap();
+    let version: VersionSlug = "1.0.0".parse().unwrap();
+    assert!(client.versions().delete(&project, &version).is_err());
+
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 1, "only mutating requests are audited");
+    assert_eq!(events[0].method, "DELETE");
+    assert_eq!(events[0].endpoint, "/v1/project/some-project/version/1.0.0");
+    assert!(events[0].error.is_some());
+}
+
+// ---------------------------------------------------------------------------
+// 7g. Typed identifiers (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_slug_validation() {
+    assert!("my-mod".parse::<ProjectSlug>().is_ok());
+    assert!("1.2.3-beta+build".parse::<VersionSlug>().is_ok());
+    assert!("".parse::<ProjectSlug>().is_err());
+    assert!("a/b".parse::<ProjectSlug>().is_err());
+    assert!("with space".parse::<Username>().is_err());
+    assert!("x?y".parse::<VersionSlug>().is_err());
+    // Dot-segments would change which endpoint the request goes to.
+    assert!(".".parse::<ProjectSlug>().is_err());
+    assert!("..".parse::<VersionSlug>().is_err());
+    assert!("...".parse::<Username>().is_err());
+    assert!(".hidden".parse::<ProjectSlug>().is_ok());
+
+    let slug: ProjectSlug = "my-mod".parse().unwrap();
+    assert_eq!(slug.to_string(), "my-mod");
+    assert_eq!(slug, "my-mod");
+}
+
+// ---------------------------------------------------------------------------
+// 7h. Partial file fetch and archive listing (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_read_range_clamps_to_file_size() {
+    let client = HubClient::new("http://127.0.0.1:9/api", None).unwrap();
+    let file = ProjectFile {
+        name: "mod.jar".into(),
+        size: 100,
+        sha1: String::new(),
+        url: "http://127.0.0.1:9/files/mod.jar".into(),
+    };
+
+    // Empty or past-the-end ranges never touch the network
+    assert!(client.files().read_range(&file, 10..10).unwrap().is_empty());
+    assert!(client
+        .files()
+        .read_range(&file, 100..200)
+        .unwrap()
+        .is_empty());
+    assert!(client.files().read_head(&file, 0).unwrap().is_empty());
+
+    // A real range is attempted and fails on the unreachable server
+    assert!(client.files().read_head(&file, 16).is_err());
+}
+
+/// Build a stored (uncompressed) zip archive in memory.
+fn build_zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let offset = out.len() as u32;
+        let len = data.len() as u32;
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&[0; 14]); // version, flags, method, time, date, crc
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&[0; 16]); // versions, flags, method, time, date, crc
+        central.extend_from_slice(&len.to_le_bytes());
+        central.extend_from_slice(&len.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0; 12]); // extra, comment, disk, attributes
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+    let cd_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&cd_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out
+}
+
+#[test]
+fn test_list_zip_entries() {
+    let zip = build_zip(&[("META-INF/", b""), ("mod.toml", b"name = \"x\"")]);
+    let entries = archive::list_entries(&zip).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries[0].is_dir());
+    assert_eq!(entries[1].name, "mod.toml");
+    assert_eq!(entries[1].size, 10);
+
+    assert!(archive::list_entries(b"not a zip at all, definitely").is_err());
+}
+
+// ---------------------------------------------------------------------------
+// 7i. Version channels (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_version_channel_parse() {
+    assert_eq!(
+        "stable".parse::<VersionChannel>().unwrap(),
+        VersionChannel::Stable
+    );
+    assert_eq!(VersionChannel::Latest.to_string(), "latest");
+    assert!("lts".parse::<VersionChannel>().is_err());
+}
+
+// ---------------------------------------------------------------------------
+// 7j. Client metrics (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_metrics_count_requests() {
+    let client = HubClient::new("http://127.0.0.1:9/api", None).unwrap();
+    assert_eq!(client.metrics().requests, 0);
+
+    assert!(client.project_types().list().is_err());
+    assert!(client.test_token().is_err());
+
+    let metrics = client.metrics();
+    assert_eq!(metrics.requests, 2);
+    assert_eq!(metrics.failed_requests, 2);
+    assert_eq!(metrics.bytes_received, 0);
+}
+
+// ---------------------------------------------------------------------------
+// 7k. Error hints and exit codes (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_error_exit_codes() {
+    let not_found = HubApiError::NotFound {
+        message: "Project not found".into(),
+        suggestions: Vec::new(),
+        response: None,
+    };
+    assert_eq!(not_found.exit_code(), 66);
+    assert!(not_found.hint().contains("slug"));
+    assert_eq!(not_found.kind(), ErrorKind::NotFound);
+    assert_eq!(not_found.status(), None);
+    assert!(!not_found.is_retryable() && !not_found.is_auth());
+
+    let server = HubApiError::Api {
+        status: 503,
+        message: "Service Unavailable".into(),
+        response: None,
+    };
+    assert_eq!(server.exit_code(), 69);
+    assert_eq!(server.kind(), ErrorKind::Unavailable);
+    assert_eq!(server.status(), Some(503));
+    assert!(server.is_retryable());
+
+    // Only I/O errors from the connection are worth retrying.
+    let io = |kind| HubApiError::Io(std::io::Error::new(kind, "io"));
+    let reset = io(std::io::ErrorKind::ConnectionReset);
+    assert_eq!(reset.kind(), ErrorKind::Network);
+    assert_eq!(reset.exit_code(), 69);
+    assert!(reset.is_retryable());
+    let denied = io(std::io::ErrorKind::PermissionDenied);
+    assert_eq!(denied.kind(), ErrorKind::Io);
+    assert_eq!(denied.exit_code(), 74);
+    assert!(!denied.is_retryable());
+
+    let client = HubClient::new("http://127.0.0.1:9/api", None)
+        .unwrap()
+        .read_only(true);
+    let err = client
+        .versions()
+        .delete(&"p".parse().unwrap(), &"1.0".parse().unwrap())
+        .unwrap_err();
+    assert_eq!(err.exit_code(), 78);
+    assert_eq!(err.kind(), ErrorKind::ReadOnly);
+}
+
+// ---------------------------------------------------------------------------
+// 7l. Async client (no server needed)
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "async")]
+#[test]
+fn test_async_client_errors() {
+    use hub01_client::AsyncHubClient;
+
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let client = AsyncHubClient::new("http://127.0.0.1:9/api", None).unwrap();
+
+    rt.block_on(async {
+        let err = client
+            .projects()
+            .list(&ListProjectsParams {
+                per_page: 7,
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HubApiError::Validation { .. }));
+
+        let err = client.project_types().list().await.unwrap_err();
+        assert!(matches!(err, HubApiError::RequestFailed(_)));
+    });
+}
+
+// ---------------------------------------------------------------------------
+// 7m. Custom transport (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_custom_transport() {
+    let fake =
+        ScriptedTransport::fixed(r#"{"data": [{"name": "Mod", "slug": "mod", "icon": ""}]}"#);
+    let client = fake.client(Some("secret"));
+
+    let types = client.project_types().list().unwrap();
+    assert_eq!(types[0].slug, "mod");
+
+    let _ = client.projects().list(&ListProjectsParams {
+        tags: Some(vec!["a b".into()]),
+        ..Default::default()
+    });
+    // Rejected before anything is sent.
+    let invalid = client.projects().list(
+        &ListProjectsParams::builder()
+            .release_date_range("2025-06-30", "2025-01-01")
+            .build(),
+    );
+    assert!(matches!(invalid, Err(HubApiError::Validation { .. })));
+
+    let _ = client.versions().create(
+        &"mod".parse().unwrap(),
+        &CreateVersionParams {
+            name: "v1".into(),
+            version: "1.0".into(),
+            release_type: "release".into(),
+            release_date: "2025-01-01".into(),
+            changelog: String::new(),
+            tags: None,
+            dependencies: None,
+        },
+        vec![("mod.jar", b"PK".to_vec().into())],
+    );
+
+    let _ = client.versions().list(
+        &"mod".parse().unwrap(),
+        &ListVersionsParams::new().search("1.20"),
+    );
+    let _ = client.projects().list(
+        &ListProjectsParams::builder()
+            .release_date_range("2025-01-01", "2025-06-30")
+            .build(),
+    );
+
+    let sent = fake.sent();
+    assert_eq!(sent[0].method, "GET");
+    assert_eq!(sent[0].url, "http://hub.invalid/api/v1/project_types");
+    assert_eq!(sent[0].header("authorization"), Some("Bearer secret"));
+    let debug = format!("{:?}", sent[0]);
+    assert!(!debug.contains("secret"));
+    assert!(debug.contains(r#"("authorization", "<redacted>")"#));
+    assert!(sent[0]
+        .header("user-agent")
+        .unwrap()
+        .starts_with("hub01-client/"));
+    assert!(sent[1].url.contains("tags%5B%5D=a+b"));
+    assert!(sent[1]
+        .url
+        .contains("order_by=downloads&order_direction=desc"));
+    assert!(!sent[1].url.contains("release_date_period"));
+    assert!(!sent[1].url.contains("release_date_start"));
+    assert!(sent[3].url.contains("search=1.20"));
+    assert!(sent[4]
+        .url
+        .contains("release_date_start=2025-01-01&release_date_end=2025-06-30"));
+    assert!(!sent[4].url.contains("release_date_period"));
+
+    assert_eq!(sent[2].method, "POST");
+    assert!(sent[2]
+        .header("content-type")
+        .unwrap()
+        .starts_with("multipart/form-data; boundary="));
+    let body = sent[2].body.as_ref().unwrap().to_vec().unwrap();
+    let body = String::from_utf8_lossy(&body);
+    assert!(body.contains("name=\"version\"\r\n\r\n1.0\r\n"));
+    assert!(body.contains("filename=\"mod.jar\""));
+}
+
+#[test]
+fn test_streamed_upload() {
+    let fake = ScriptedTransport::fixed("{}");
+    let client = fake.client(None);
+    let path = std::env::temp_dir().join(format!("hub01-upload-test-{}.jar", std::process::id()));
+    fs::write(&path, b"jar from disk").unwrap();
+
+    let version: VersionSlug = "1.0".parse().unwrap();
+    let _ = client.versions().update(
+        &"mod".parse().unwrap(),
+        &version,
+        &hub01_client::UpdateVersionParams::default(),
+        Some(vec![
+            ("a.jar", FileSource::path(&path)),
+            ("b.txt", FileSource::reader(&b"from a reader"[..])),
+        ]),
+    );
+    let _ = client.versions().update(
+        &"mod".parse().unwrap(),
+        &version,
+        &hub01_client::UpdateVersionParams::default(),
+        Some(vec![("a.jar", FileSource::path(&path))]),
+    );
+
+    let sent = fake.sent();
+    // Files are only read when the transport reads the body.
+    let streamed = sent[0].body.as_ref().unwrap();
+    assert_eq!(streamed.len(), None);
+    let body = String::from_utf8(streamed.to_vec().unwrap()).unwrap();
+    assert!(body.contains(
+        "filename=\"a.jar\"\r\nContent-Type: application/octet-stream\r\n\r\njar from disk\r\n"
+    ));
+    assert!(body.contains("\r\n\r\nfrom a reader\r\n--"));
+    assert!(streamed.to_vec().is_err(), "readers cannot be replayed");
+
+    // With only sized sources the length is known up front.
+    let body = sent[1].body.as_ref().unwrap();
+    assert_eq!(body.len(), Some(body.to_vec().unwrap().len() as u64));
+
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn test_app_name_user_agent() {
+    let fake = ScriptedTransport::fixed(r#"{"data": []}"#);
+    let client = fake.client(None).app_name("MyLauncher/2.1\n");
+    client.project_types().list().unwrap();
+
+    let sent = fake.sent();
+    let agent = sent[0].header("user-agent").unwrap();
+    assert!(agent.starts_with("hub01-client/"));
+    assert!(agent.ends_with(" MyLauncher/2.1"));
+}
+
+#[test]
+fn test_list_all_walks_pages() {
+    let client = ScriptedTransport::paged(3).client(None);
+    let slugs: Vec<String> = client
+        .projects()
+        .list_all(&ListProjectsParams::default())
+        .map(|p| p.unwrap().slug.to_string())
+        .collect();
+    assert_eq!(slugs, ["p1a", "p1b", "p2a", "p2b", "p3a", "p3b"]);
+
+    // Starts from params.page
+    let from_two = client
+        .projects()
+        .list_all(&ListProjectsParams {
+            page: 2,
+            ..Default::default()
+        })
+        .count();
+    assert_eq!(from_two, 4);
+}
+
+#[test]
+fn test_page_links_keep_token_on_api_origin() {
+    let fake = ScriptedTransport::fixed(
+        r#"{"data": [], "links": {
+            "next": "https://elsewhere.invalid/api/v1/projects?page=2",
+            "prev": "http://hub.invalid/api/v1/projects?page=0"
+        }}"#,
+    );
+    let client = fake.client(Some("secret"));
+    let page = client
+        .projects()
+        .list(&ListProjectsParams::default())
+        .unwrap();
+    page.next_page(&client).unwrap();
+    page.prev_page(&client).unwrap();
+
+    let sent = fake.sent();
+    assert_eq!(
+        sent[1].url,
+        "https://elsewhere.invalid/api/v1/projects?page=2"
+    );
+    assert_eq!(sent[1].header("authorization"), None);
+    assert_eq!(sent[2].header("authorization"), Some("Bearer secret"));
+}
+
+#[test]
+fn test_handles_walk_versions_and_download() {
+    let version = |v: &str| {
+        serde_json::json!({
+            "name": v, "version": v, "release_type": "release",
+            "release_date": "2025-01-01", "changelog": null, "downloads": 0,
+            "files": [
+                {"name": "../mod.jar", "size": 11, "sha1": "", "url": "http://hub.invalid/files/mod.jar"},
+                {"name": "sources.jar", "size": 0, "sha1": "", "url": "http://hub.invalid/files/sources.jar"},
+            ],
+        })
+    };
+    let fake = ScriptedTransport::new(move |request, _| {
+        let path = request.url.split('?').next().unwrap();
+        let body = match path {
+            "http://hub.invalid/api/v1/project/mod/versions" => {
+                if query_param(request, "page") == Some("2") {
+                    serde_json::json!({"data": [version("1.0")], "links": {"next": null}})
+                } else {
+                    serde_json::json!({
+                        "data": [version("2.0"), version("1.1")],
+                        "links": {"next": "http://hub.invalid/api/v1/project/mod/versions?page=2"},
+                    })
+                }
+            }
+            "http://hub.invalid/api/v1/project/mod/version/2.0" => {
+                serde_json::json!({"data": version("2.0")})
+            }
+            "http://hub.invalid/files/mod.jar" => return Reply::new(200, "jar content"),
+            other => panic!("unexpected request to {other}"),
+        };
+        Reply::json(body.to_string())
+    });
+    let client = fake.client(None);
+    let project = client.project("mod").unwrap();
+
+    let versions: Vec<String> = project
+        .all_versions(&ListVersionsParams::default())
+        .map(|v| v.unwrap().version.to_string())
+        .collect();
+    assert_eq!(versions, ["2.0", "1.1", "1.0"]);
+
+    let dir = std::env::temp_dir().join(format!("hub01-primary-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = project
+        .version("2.0")
+        .unwrap()
+        .download_primary(&dir)
+        .unwrap();
+    // The file name cannot point outside the directory.
+    assert_eq!(path, dir.join("mod.jar"));
+    assert_eq!(fs::read(&path).unwrap(), b"jar content");
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_client_builder() {
+    let client = HubClient::builder("http://127.0.0.1:9/api")
+        .token("secret")
+        .connect_timeout(Duration::from_secs(1))
+        .timeout(Duration::from_secs(2))
+        .no_proxy()
+        .app_name("tests/1.0")
+        .build()
+        .unwrap();
+    match client.project_types().list() {
+        Err(HubApiError::RequestFailed(_)) => {}
+        other => panic!("Expected RequestFailed, got {other:?}"),
+    }
+
+    let err = HubClient::builder("http://127.0.0.1:9/api")
+        .proxy("http://[::1")
+        .build()
+        .err()
+        .expect("invalid proxy URL accepted");
+    assert!(matches!(err, HubApiError::RequestFailed(_)));
+}
+
+// ---------------------------------------------------------------------------
+// 7n. Retries (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_retry_transient_failures() {
+    let policy = RetryPolicy {
+        max_retries: 2,
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        jitter: true,
+    };
+    let flaky = |failures| {
+        let fake = ScriptedTransport::flaky(failures, 503);
+        (fake.client(Some("secret")).retry(policy.clone()), fake)
+    };
+
+    let (client, fake) = flaky(2);
+    assert!(client.project_types().list().unwrap().is_empty());
+    assert_eq!(fake.calls(), 3);
+
+    // Gives up after max_retries
+    let (client, fake) = flaky(5);
+    match client.project_types().list() {
+        Err(HubApiError::Maintenance { .. }) => {}
+        other => panic!("Expected Maintenance, got {other:?}"),
+    }
+    assert_eq!(fake.calls(), 3);
+
+    // Mutations are never repeated
+    let (client, fake) = flaky(5);
+    let _ = client
+        .versions()
+        .delete(&"mod".parse().unwrap(), &"v1".parse().unwrap());
+    assert_eq!(fake.calls(), 1);
+
+    assert!(policy.backoff(10) <= policy.max_backoff);
+    assert!(!RetryPolicy::is_transient(&HubApiError::Api {
+        status: 500,
+        message: String::new(),
+        response: None,
+    }));
+    // Same rule for I/O errors as HubApiError::is_retryable.
+    let io = |kind| HubApiError::Io(std::io::Error::new(kind, "io"));
+    assert!(RetryPolicy::is_transient(&io(
+        std::io::ErrorKind::ConnectionReset
+    )));
+    assert!(!RetryPolicy::is_transient(&io(
+        std::io::ErrorKind::PermissionDenied
+    )));
+}
+
+#[test]
+fn test_rate_limited() {
+    let limited = |wait| {
+        let fake = ScriptedTransport::flaky(2, 429);
+        let mut client = fake.client(Some("secret"));
+        if wait {
+            client = client.wait_on_rate_limit(Duration::from_secs(1));
+        }
+        (client, fake)
+    };
+
+    let (client, _) = limited(false);
+    let err = client.project_types().list().unwrap_err();
+    match err {
+        HubApiError::RateLimited { retry_after, .. } => {
+            assert_eq!(retry_after, Some(Duration::ZERO))
+        }
+        ref other => panic!("Expected RateLimited, got {other:?}"),
+    }
+    assert_eq!(err.exit_code(), 75);
+    assert_eq!(err.status(), Some(429));
+    assert!(err.is_retryable());
+
+    let (client, fake) = limited(true);
+    assert!(client.project_types().list().unwrap().is_empty());
+    assert_eq!(fake.calls(), 3);
+
+    // A 429 request was not processed, so mutations are resent too
+    let (client, fake) = limited(true);
+    let _ = client
+        .versions()
+        .delete(&"mod".parse().unwrap(), &"v1".parse().unwrap());
+    assert_eq!(fake.calls(), 3);
+}
+
+#[test]
+fn test_maintenance_mode() {
+    let unavailable = |body: &'static str| {
+        ScriptedTransport::new(move |_, _| Reply::new(503, body).header("Retry-After", "120"))
+            .client(None)
+            .project_types()
+            .list()
+            .unwrap_err()
+    };
+
+    match unavailable(r#"{"message": "Back soon"}"#) {
+        HubApiError::Maintenance {
+            message,
+            retry_after,
+            ..
+        } => {
+            assert_eq!(message, "Back soon");
+            assert_eq!(retry_after, Some(Duration::from_secs(120)));
+        }
+        other => panic!("Expected Maintenance, got {other:?}"),
+    }
+
+    let html = "<html><head><TITLE> Down for maintenance </TITLE></head></html>";
+    // The raw exchange is kept for debugging.
+    let err = unavailable(html);
+    let response = err.response().expect("error response kept");
+    assert_eq!(response.method, "GET");
+    assert_eq!(response.url, "http://hub.invalid/api/v1/project_types");
+    assert_eq!(response.status, 503);
+    assert_eq!(response.header("retry-after"), Some("120"));
+    assert_eq!(response.body, html);
+    assert!(response
+        .to_string()
+        .starts_with("GET http://hub.invalid/api/v1/project_types -> 503\n"));
+    match err {
+        HubApiError::Maintenance { ref message, .. } => {
+            assert_eq!(message, "Down for maintenance")
+        }
+        other => panic!("Expected Maintenance, got {other:?}"),
+    }
+
+    // Without a body it is an ordinary outage.
+    assert!(matches!(
+        unavailable(""),
+        HubApiError::Api { status: 503, .. }
+    ));
+    assert_eq!(unavailable("{}").exit_code(), 69);
+}
+
+// ---------------------------------------------------------------------------
+// 7o. Tracing spans (no server needed)
+// ---------------------------------------------------------------------------
+
+/// Collects `name=value` for every field recorded on any span.
+#[cfg(feature = "tracing")]
+#[derive(Clone, Default)]
+struct FieldRecorder(Arc<Mutex<Vec<String>>>);
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for FieldRecorder {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{}={value:?}", field.name()));
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::Subscriber for FieldRecorder {
+    fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+    fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        span.record(&mut self.clone());
+        tracing::span::Id::from_u64(1)
+    }
+    fn record(&self, _: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        values.record(&mut self.clone());
+    }
+    fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+    fn event(&self, _: &tracing::Event<'_>) {}
+    fn enter(&self, _: &tracing::span::Id) {}
+    fn exit(&self, _: &tracing::span::Id) {}
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn test_tracing_spans() {
+    let recorder = FieldRecorder::default();
+    let client = ScriptedTransport::fixed(r#"{"data": []}"#).client(None);
+    tracing::subscriber::with_default(recorder.clone(), || {
+        client.project_types().list().unwrap();
+    });
 
-fn base_url() -> String {
-    std::env::var("HUB01_BASE_URL").unwrap_or_else(|_| "https://hub01-shop.srgnis.com/api".into())
+    let fields = recorder.0.lock().unwrap();
+    assert!(fields.contains(&"method=\"GET\"".to_string()));
+    assert!(fields.contains(&"endpoint=\"/v1/project_types\"".to_string()));
+    assert!(fields.contains(&"status=200".to_string()));
+    assert!(fields.iter().any(|f| f.starts_with("latency_ms=")));
 }
 
-fn credentials() -> (Option<String>, Option<String>) {
-    let username = std::env::var("HUB01_USERNAME")
-        .ok()
-        .or_else(|| read_credential_file("username"));
-    let token = std::env::var("HUB01_TOKEN")
-        .ok()
-        .or_else(|| read_credential_file("api_key"));
-    (username, token)
+// ---------------------------------------------------------------------------
+// 7p. Tag resolution (no server needed)
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_tag_resolver_plain_listing() {
+    let tag = |name: &str, slug: &str, main: Option<&str>| {
+        serde_json::from_value::<ProjectTag>(serde_json::json!({
+            "name": name, "slug": slug, "icon": "", "tag_group": null,
+            "project_types": ["mod"], "main_tag": main,
+        }))
+        .unwrap()
+    };
+    // Plain listings are flat; sub-tags point at their parent by name or slug.
+    let resolver = TagResolver::new(vec![
+        tag("Combat", "combat", Some("Gameplay")),
+        tag("Gameplay", "gameplay", None),
+        tag("Magic", "magic", Some("gameplay")),
+        tag("Loop", "loop", Some("loop")),
+    ]);
+    assert_eq!(resolver.len(), 4);
+    assert_eq!(resolver.display_path("combat"), "Gameplay > Combat");
+    assert_eq!(resolver.display_path("magic"), "Gameplay > Magic");
+    assert_eq!(resolver.display_path("gameplay"), "Gameplay");
+    assert_eq!(resolver.display_path("loop"), "Loop");
+    assert!(resolver.resolve("missing").is_none());
 }
 
 // ---------------------------------------------------------------------------
-// 1. Project types
+// 7q. Conditional requests (no server needed)
 // ---------------------------------------------------------------------------
 
 #[test]
-fn test_list_project_types() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let types = client.project_types().list().unwrap();
-    assert!(!types.is_empty(), "Expected at least one project type");
-    println!("[1] Found {} project types", types.len());
-    for t in types.iter().take(3) {
-        println!("  - {} ({})", t.name, t.slug);
+fn test_conditional_requests() {
+    // Serves a fixed body with an `ETag`, answering 304 when the client
+    // sends that ETag back.
+    let fake = ScriptedTransport::new(|request, _| {
+        if request.header("if-none-match") == Some("\"v1\"") {
+            Reply::new(304, "")
+        } else {
+            Reply::json(r#"{"data": [{"name": "Mod", "slug": "mod", "icon": ""}]}"#)
+        }
+        .header("ETag", "\"v1\"")
+    });
+    let client = fake.client(None).conditional_requests(true);
+
+    for _ in 0..2 {
+        let types = client.project_types().list().unwrap();
+        assert_eq!(types[0].slug, "mod");
     }
+    assert_eq!(client.metrics().not_modified, 1);
+
+    let sent = fake.sent();
+    assert_eq!(sent[0].header("if-none-match"), None);
+    assert_eq!(sent[1].header("if-none-match"), Some("\"v1\""));
 }
 
 // ---------------------------------------------------------------------------
-// 2. Project tags
+// 7r. Disk cache (no server needed)
 // ---------------------------------------------------------------------------
 
 #[test]
-fn test_list_project_tags() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let tags = client.tags().list_project_tags(true, None).unwrap();
-    assert!(!tags.is_empty(), "Expected at least one project tag");
-    println!("[2] Found {} project tags", tags.len());
-    println!("  - First tag: {} ({})", tags[0].name, tags[0].slug);
+fn test_disk_cache_fallback_and_offline() {
+    let dir = std::env::temp_dir().join(format!("hub01-cache-test-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
 
-    // Fetch a specific tag
-    let detail = client.tags().get_project_tag(&tags[0].slug).unwrap();
-    println!("  - Tag icon: {}", detail.icon);
+    // Online: the response is stored.
+    let online =
+        ScriptedTransport::fixed(r#"{"data": [{"name": "Mod", "slug": "mod", "icon": ""}]}"#)
+            .client(None)
+            .disk_cache(&dir, CachePolicy::default());
+    assert_eq!(online.project_types().list().unwrap()[0].slug, "mod");
+    assert_eq!(online.metrics().cache_hits, 0);
+
+    // Server down: the stored response is served instead of the 503.
+    let down = ScriptedTransport::flaky(usize::MAX, 503)
+        .client(None)
+        .disk_cache(&dir, CachePolicy::default());
+    assert_eq!(down.project_types().list().unwrap()[0].slug, "mod");
+    assert_eq!(down.metrics().cache_hits, 1);
+
+    // Offline: no request is sent; misses fail with a network error.
+    let fake = ScriptedTransport::fixed("{}");
+    let offline = fake.client(None).disk_cache(&dir, CachePolicy::offline());
+    assert_eq!(offline.project_types().list().unwrap()[0].slug, "mod");
+    let miss = offline.project_types().get("mod").unwrap_err();
+    assert!(matches!(miss, HubApiError::Io(_)));
+    assert_eq!(miss.kind(), ErrorKind::Network);
+    assert_eq!(
+        miss.hint(),
+        "Check your network connection and the instance URL."
+    );
+    assert_eq!(fake.calls(), 0);
+
+    let _ = fs::remove_dir_all(&dir);
 }
 
 // ---------------------------------------------------------------------------
-// 3. Version tags
+// 7s. Badges (no server needed)
 // ---------------------------------------------------------------------------
 
 #[test]
-fn test_list_version_tags() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let tags = client.tags().list_version_tags(true, None).unwrap();
-    assert!(!tags.is_empty(), "Expected at least one version tag");
-    println!("[3] Found {} version tags", tags.len());
-    println!("  - First tag: {}", tags[0].name);
+fn test_badges() {
+    assert_eq!(badge::compact_count(999), "999");
+    assert_eq!(badge::compact_count(1_000), "1.0k");
+    assert_eq!(badge::compact_count(999_960), "1.0M");
+
+    let project: hub01_client::Project = serde_json::from_value(fake_project("a<b")).unwrap();
+    let svg = badge::downloads(&project);
+    assert!(svg.contains(">0</text>"));
+
+    let svg = badge::svg("ver<sion", "1.0 & up", badge::GREEN);
+    assert!(svg.contains("ver&lt;sion"));
+    assert!(svg.contains("1.0 &amp; up"));
+    assert!(!svg.contains("1.0 & up"));
 }
 
 // ---------------------------------------------------------------------------
-// 4. List projects
+// 7t. File downloads (no server needed)
 // ---------------------------------------------------------------------------
 
 #[test]
-fn test_list_projects() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let resp = client
-        .projects()
-        .list(&ListProjectsParams {
-            per_page: 10,
-            ..Default::default()
-        })
+fn test_download_file() {
+    let file = ProjectFile {
+        name: "mod.jar".into(),
+        size: 11,
+        sha1: String::new(),
+        url: "http://hub.invalid/files/mod.jar".into(),
+    };
+    let fake = ScriptedTransport::fixed("jar content");
+    let client = fake.client(Some("secret"));
+
+    let mut out = Vec::new();
+    assert_eq!(client.files().download(&file, &mut out).unwrap(), 11);
+    assert_eq!(out, b"jar content");
+    assert_eq!(fake.sent()[0].url, file.url);
+    assert_eq!(
+        fake.sent()[0].header("authorization"),
+        Some("Bearer secret")
+    );
+
+    let dir = std::env::temp_dir().join(format!("hub01-download-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("mod.jar");
+    client.files().download_to_path(&file, &path).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"jar content");
+
+    // A failed download leaves neither the file nor the partial one behind.
+    let failing = ScriptedTransport::flaky(usize::MAX, 404).client(None);
+    let missing = dir.join("missing.jar");
+    assert!(matches!(
+        failing.files().download_to_path(&file, &missing),
+        Err(HubApiError::NotFound { .. })
+    ));
+    assert!(!missing.exists());
+    assert!(!dir.join("missing.jar.part").exists());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_download_token_stays_on_api_origin() {
+    let fake = ScriptedTransport::new(|_, _| Reply::new(200, "jar content"));
+    let client = fake.client(Some("secret"));
+    let file = |url: &str| ProjectFile {
+        name: "mod.jar".into(),
+        size: 11,
+        sha1: String::new(),
+        url: url.into(),
+    };
+
+    for url in [
+        "http://hub.invalid/storage/mod.jar",
+        "https://cdn.invalid/mod.jar",
+        "http://hub.invalid:8080/mod.jar",
+    ] {
+        client
+            .files()
+            .download(&file(url), &mut Vec::new())
+            .unwrap();
+    }
+    // Ranged reads follow the same rule.
+    let head = client
+        .files()
+        .read_head(&file("https://cdn.invalid/mod.jar"), 3)
         .unwrap();
-    assert!(!resp.data.is_empty(), "Expected at least one project");
-    println!("[4] Found {} projects (page 1)", resp.data.len());
-    for p in resp.data.iter().take(3) {
-        println!("  - {} (downloads: {})", p.name, p.downloads);
+    assert_eq!(head, b"jar");
+    let sent = fake.sent();
+    assert_eq!(sent[0].header("authorization"), Some("Bearer secret"));
+    assert_eq!(sent[1].header("authorization"), None);
+    assert_eq!(sent[2].header("authorization"), None);
+    assert_eq!(sent[3].header("range"), Some("bytes=0-2"));
+    assert_eq!(sent[3].header("authorization"), None);
+    // Everything else is still sent.
+    assert!(sent[1].header("user-agent").is_some());
+}
+
+#[test]
+fn test_download_checksum() {
+    let client = || ScriptedTransport::fixed("jar content").client(None);
+    let file = |sha1: &str| ProjectFile {
+        name: "mod.jar".into(),
+        size: 11,
+        sha1: sha1.into(),
+        url: "http://hub.invalid/files/mod.jar".into(),
+    };
+
+    // Digests are compared case-insensitively.
+    let good = file("98E8C388609D8EB82FA1FE3AB08DFE892C4F4C95");
+    let mut out = Vec::new();
+    assert_eq!(client().files().download(&good, &mut out).unwrap(), 11);
+
+    let bad = file("da39a3ee5e6b4b0d3255bfef95601890afd80709");
+    match client().files().download(&bad, &mut Vec::new()) {
+        Err(HubApiError::ChecksumMismatch { expected, actual }) => {
+            assert_eq!(expected, "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+            assert_eq!(actual, "98e8c388609d8eb82fa1fe3ab08dfe892c4f4c95");
+        }
+        other => panic!("expected ChecksumMismatch, got {other:?}"),
+    }
+
+    let dir = std::env::temp_dir().join(format!("hub01-checksum-test-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("mod.jar");
+    assert!(client().files().download_to_path(&bad, &path).is_err());
+    assert!(!path.exists());
+    assert!(!dir.join("mod.jar.part").exists());
+
+    client()
+        .verify_checksums(false)
+        .files()
+        .download_to_path(&bad, &path)
+        .unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"jar content");
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_download_progress() {
+    let client = ScriptedTransport::fixed("jar content").client(None);
+    let file = ProjectFile {
+        name: "mod.jar".into(),
+        size: 11,
+        sha1: String::new(),
+        url: "http://hub.invalid/files/mod.jar".into(),
+    };
+
+    let mut reports = Vec::new();
+    let mut out = Vec::new();
+    client
+        .files()
+        .download_with_progress(&file, &mut out, |p| reports.push(*p))
+        .unwrap();
+    assert_eq!(out, b"jar content");
+    let last = reports.last().unwrap();
+    assert_eq!(last.transferred, 11);
+    assert_eq!(last.total, Some(11));
+    assert_eq!(last.eta, Some(Duration::ZERO));
+    assert_eq!(last.fraction(), Some(1.0));
+    assert!(reports
+        .windows(2)
+        .all(|w| w[0].transferred <= w[1].transferred));
+
+    // Reports are throttled: back-to-back writes produce one callback.
+    let mut count = 0;
+    let mut writer = progress::ProgressWriter::new(Vec::new(), None, |_| count += 1);
+    for _ in 0..100 {
+        writer.write_all(b"x").unwrap();
     }
+    let progress = writer.progress();
+    assert_eq!(progress.transferred, 100);
+    assert_eq!(progress.eta, None);
+    drop(writer);
+    assert_eq!(count, 1);
 }
 
 // ---------------------------------------------------------------------------
-// 5. Filter / search projects
+// 7w. Date parsing (`chrono` feature, no server needed)
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "chrono")]
 #[test]
-fn test_filter_projects() {
-    let client = HubClient::new(&base_url(), None).unwrap();
+fn test_chrono_dates() {
+    use chrono::{TimeZone, Utc};
+    use hub01_client::{Project, ProjectVersion};
 
-    // First list to get a search term
-    let resp = client
-        .projects()
-        .list(&ListProjectsParams::default())
-        .unwrap();
-    if let Some(first) = resp.data.first() {
-        let term = first.name.split_whitespace().next().unwrap_or(&first.name);
-        let search = client
-            .projects()
-            .list(&ListProjectsParams {
-                search: Some(term.to_string()),
-                per_page: 10,
-                ..Default::default()
-            })
-            .unwrap();
-        println!(
-            "[5] Search for '{}' returned {} results",
-            term,
-            search.data.len()
-        );
+    let mut json = fake_project("mod");
+    json["updated_at"] = "2025-03-04 05:06:07".into();
+    json["last_release_date"] = "not a date".into();
+    let project: Project = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        project.created_at_utc(),
+        Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+    );
+    assert_eq!(
+        project.updated_at_utc(),
+        Some(Utc.with_ymd_and_hms(2025, 3, 4, 5, 6, 7).unwrap())
+    );
+    assert_eq!(project.last_release_date_utc(), None);
 
-        // Order by name ASC
-        let filtered = client
-            .projects()
-            .list(&ListProjectsParams {
-                order_by: Some("name".into()),
-                order_direction: Some("asc".into()),
-                per_page: 10,
-                ..Default::default()
-            })
-            .unwrap();
-        println!(
-            "[5] Filtered by type 'mod', ordered by name: {} results",
-            filtered.data.len()
-        );
-    }
+    let version: ProjectVersion = serde_json::from_value(serde_json::json!({
+        "name": "v1", "version": "1.0", "release_type": "release",
+        "release_date": "2025-06-30", "changelog": null, "downloads": 0,
+    }))
+    .unwrap();
+    assert_eq!(
+        version.release_date_utc(),
+        Some(Utc.with_ymd_and_hms(2025, 6, 30, 0, 0, 0).unwrap())
+    );
 }
 
 // ---------------------------------------------------------------------------
-// 6. List versions of a project
+// 7x. Semver helpers (`semver` feature, no server needed)
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "semver")]
 #[test]
-fn test_list_versions() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let projects = client
-        .projects()
-        .list(&ListProjectsParams::default())
-        .unwrap();
-    let slug = &projects.data[0].slug;
+fn test_semver_helpers() {
+    use semver::VersionReq;
 
-    let versions = client
+    // A project's versions over two pages, in release order.
+    let client = ScriptedTransport::new(|request, _| {
+        let version = |v: &str| {
+            serde_json::json!({
+                "name": v, "version": v, "release_type": "release",
+                "release_date": "2025-01-01", "changelog": null, "downloads": 0,
+            })
+        };
+        let body = if query_param(request, "page") == Some("2") {
+            serde_json::json!({
+                "data": [version("1.9.3"), version("2024w10a")],
+                "links": { "next": null },
+            })
+        } else {
+            serde_json::json!({
+                "data": [version("v2.0"), version("1.10.0-beta.1"), version("1.2")],
+                "links": { "next": "http://hub.invalid/api/v1/project/mod/versions?page=2" },
+            })
+        };
+        Reply::json(body.to_string())
+    })
+    .client(None);
+    let slug: ProjectSlug = "mod".parse().unwrap();
+    let latest = |req: &str| {
+        client
+            .versions()
+            .latest_matching(&slug, &VersionReq::parse(req).unwrap())
+            .map(|v| v.version.to_string())
+    };
+
+    // Found on the second page although released earlier.
+    assert_eq!(latest("^1").unwrap(), "1.9.3");
+    assert_eq!(latest(">=1.10.0-beta").unwrap(), "v2.0");
+    assert_eq!(latest(">=1.10.0-beta, <2").unwrap(), "1.10.0-beta.1");
+    assert_eq!(latest("~1.2").unwrap(), "1.2");
+    assert!(matches!(latest("^3"), Err(HubApiError::NotFound { .. })));
+
+    let page = client
         .versions()
-        .list(slug, &ListVersionsParams::default())
+        .list(&slug, &ListVersionsParams::default())
         .unwrap();
-    println!(
-        "[6] Project '{}' has {} versions",
-        slug,
-        versions.data.len()
+    let parsed: Vec<_> = page.data.iter().map(|v| v.semver()).collect();
+    assert_eq!(parsed[0], Some(semver::Version::new(2, 0, 0)));
+    assert_eq!(parsed[2], Some(semver::Version::new(1, 2, 0)));
+}
+
+// ---------------------------------------------------------------------------
+// 7y. OpenAPI contract (needs HUB01_OPENAPI_SPEC, no server needed)
+// ---------------------------------------------------------------------------
+
+/// Send one request through every client endpoint and return what was sent.
+fn record_all_endpoints() -> Vec<HttpRequest> {
+    let fake = ScriptedTransport::fixed("{}");
+    let client = fake.client(Some("token")).verify_checksums(false);
+    let project: ProjectSlug = "mod".parse().unwrap();
+    let version: VersionSlug = "1.0".parse().unwrap();
+    let tag: hub01_client::TagSlug = "magic".parse().unwrap();
+    let user: Username = "alice".parse().unwrap();
+
+    let _ = client.test_token();
+    let _ = client.project_types().list();
+    let _ = client.project_types().get("mod");
+    let _ = client.projects().list(
+        &ListProjectsParams::builder()
+            .search("x")
+            .tags(["a"])
+            .version_tags(["b"])
+            .release_date_range("2025-01-01", "2025-12-31")
+            .build(),
     );
-    for v in versions.data.iter().take(3) {
-        println!(
-            "  - {} ({}, downloads: {})",
-            v.version, v.release_type, v.downloads
-        );
+    let _ = client.projects().get(&project);
+    let _ = client
+        .versions()
+        .list(&project, &ListVersionsParams::new().search("x").tags(["a"]));
+    let _ = client.versions().get(&project, &version);
+    let _ = client.versions().create(
+        &project,
+        &CreateVersionParams {
+            name: "v".into(),
+            version: "1.0".into(),
+            release_type: "release".into(),
+            release_date: "2025-01-01".into(),
+            changelog: String::new(),
+            tags: None,
+            dependencies: None,
+        },
+        Vec::new(),
+    );
+    let _ = client.versions().update(
+        &project,
+        &version,
+        &hub01_client::UpdateVersionParams::default(),
+        None,
+    );
+    let _ = client.versions().delete(&project, &version);
+    let _ = client.tags().list_project_tags(true, Some("mod"));
+    let _ = client.tags().get_project_tag(&tag);
+    let _ = client.tags().list_version_tags(true, Some("mod"));
+    let _ = client.tags().get_version_tag(&tag);
+    let _ = client.users().get(&user);
+    let _ = client.users().get_projects(&user);
+
+    let sent = fake.sent().clone();
+    sent
+}
+
+/// Check each request's path, method and query parameter names against an
+/// OpenAPI 3 document, returning one message per mismatch.
+fn contract_violations(spec: &serde_json::Value, requests: &[HttpRequest]) -> Vec<String> {
+    let resolve = |v: &serde_json::Value| -> serde_json::Value {
+        match v.get("$ref").and_then(|r| r.as_str()) {
+            Some(r) => spec
+                .pointer(r.trim_start_matches('#'))
+                .cloned()
+                .unwrap_or_default(),
+            None => v.clone(),
+        }
+    };
+    let paths = spec["paths"].as_object().cloned().unwrap_or_default();
+    let mut violations = Vec::new();
+    for request in requests {
+        let (path, query) = request.url.split_once('?').unwrap_or((&request.url, ""));
+        // The spec may or may not include the `/api` prefix.
+        let path = &path[path.find("/v1/").or_else(|| path.rfind('/')).unwrap()..];
+        let segments: Vec<&str> = path.split('/').collect();
+        let found = paths.iter().find(|(template, _)| {
+            let template = template.strip_prefix("/api").unwrap_or(template);
+            let parts: Vec<&str> = template.split('/').collect();
+            parts.len() == segments.len()
+                && parts
+                    .iter()
+                    .zip(&segments)
+                    .all(|(t, s)| t == s || (t.starts_with('{') && t.ends_with('}')))
+        });
+        let Some((template, item)) = found else {
+            violations.push(format!("{} {path}: no such path", request.method));
+            continue;
+        };
+        let Some(operation) = item.get(request.method.to_ascii_lowercase()) else {
+            violations.push(format!(
+                "{} {path}: method not allowed by {template}",
+                request.method
+            ));
+            continue;
+        };
+        let documented: Vec<String> = [&item["parameters"], &operation["parameters"]]
+            .into_iter()
+            .filter_map(|p| p.as_array())
+            .flatten()
+            .map(resolve)
+            .filter(|p| p["in"] == "query")
+            .filter_map(|p| {
+                p["name"]
+                    .as_str()
+                    .map(|n| n.trim_end_matches("[]").to_string())
+            })
+            .collect();
+        for pair in query.split('&').filter(|p| !p.is_empty()) {
+            let name = pair.split('=').next().unwrap().replace("%5B%5D", "");
+            if !documented.contains(&name) {
+                violations.push(format!(
+                    "{} {path}: undocumented query parameter `{name}`",
+                    request.method
+                ));
+            }
+        }
     }
+    violations.sort();
+    violations.dedup();
+    violations
+}
+
+#[test]
+fn test_openapi_contract_checker() {
+    let spec = serde_json::json!({
+        "paths": {
+            "/api/v1/project_types": { "get": {} },
+            "/api/v1/project/{slug}": {
+                "parameters": [{ "$ref": "#/components/parameters/Slug" }],
+                "get": { "parameters": [{ "name": "include", "in": "query" }] }
+            }
+        },
+        "components": { "parameters": { "Slug": { "name": "slug", "in": "path" } } }
+    });
+    let request = |method, url: &str| HttpRequest {
+        method,
+        url: url.into(),
+        headers: Vec::new(),
+        body: None,
+    };
+    let requests = [
+        request("GET", "http://hub.invalid/api/v1/project_types"),
+        request("GET", "http://hub.invalid/api/v1/project/mod?include=x"),
+        request("DELETE", "http://hub.invalid/api/v1/project/mod"),
+        request("GET", "http://hub.invalid/api/v1/project/mod?tags%5B%5D=a"),
+        request("GET", "http://hub.invalid/api/v1/projects"),
+    ];
+    assert_eq!(
+        contract_violations(&spec, &requests),
+        [
+            "DELETE /v1/project/mod: method not allowed by /api/v1/project/{slug}",
+            "GET /v1/project/mod: undocumented query parameter `tags`",
+            "GET /v1/projects: no such path",
+        ]
+    );
+}
+
+/// Validate every endpoint the client calls against the server's OpenAPI
+/// document (JSON), e.g. one exported from the shop in CI.
+#[test]
+fn test_openapi_contract() {
+    let Ok(spec_path) = std::env::var("HUB01_OPENAPI_SPEC") else {
+        println!("[7y] Skipping OpenAPI contract test (set HUB01_OPENAPI_SPEC)");
+        return;
+    };
+    let spec: serde_json::Value = serde_json::from_slice(&fs::read(&spec_path).unwrap()).unwrap();
+    let violations = contract_violations(&spec, &record_all_endpoints());
+    assert!(
+        violations.is_empty(),
+        "client drifted from {spec_path}:\n{}",
+        violations.join("\n")
+    );
 }
 
 // ---------------------------------------------------------------------------
-// 7. Filter versions + get details
+// 7z. Version ordering (no server needed)
 // ---------------------------------------------------------------------------
 
 #[test]
-fn test_filter_versions_and_details() {
-    let client = HubClient::new(&base_url(), None).unwrap();
-    let projects = client
-        .projects()
-        .list(&ListProjectsParams::default())
-        .unwrap();
-    let slug = &projects.data[0].slug;
+fn test_version_ordering() {
+    use hub01_client::versions;
+    use std::cmp::Ordering;
 
-    let versions = client
-        .versions()
-        .list(
-            slug,
-            &ListVersionsParams {
-                order_by: "release_date".into(),
-                order_direction: "desc".into(),
-                ..Default::default()
-            },
-        )
-        .unwrap();
-    println!(
-        "[7] Filtered versions (by release_date desc): {} results",
-        versions.data.len()
+    let mut list = vec![
+        "2.0",
+        "1.10",
+        "1.2.0",
+        "v1.2",
+        "1.2-rc.10",
+        "1.2-RC.2",
+        "1.2-beta",
+        "1.2-alpha.1",
+        "1.2-alpha",
+        "1.9.1+build5",
+        "0.99",
+    ];
+    list.sort_by(|a, b| versions::compare(a, b));
+    assert_eq!(
+        list,
+        [
+            "0.99",
+            "1.2-alpha",
+            "1.2-alpha.1",
+            "1.2-beta",
+            "1.2-RC.2",
+            "1.2-rc.10",
+            "1.2.0",
+            "v1.2",
+            "1.9.1+build5",
+            "1.10",
+            "2.0",
+        ]
+    );
+    assert_eq!(versions::compare("1.0", "1.0"), Ordering::Equal);
+    assert_eq!(
+        versions::compare("99999999999999999999.1", "3"),
+        Ordering::Greater
     );
 
-    if let Some(v) = versions.data.first() {
-        let detail = client.versions().get(slug, &v.version).unwrap();
-        println!("[7] Version details: {}", detail.version);
-        println!("  - Files: {}", detail.files.len());
-        println!("  - Dependencies: {}", detail.dependencies.len());
-    }
+    // Several versions released the same day: resolve picks by version.
+    let client = ScriptedTransport::fixed(
+        r#"{"data": [
+                {"name": "a", "version": "1.9", "release_type": "release",
+                 "release_date": "2025-03-01", "changelog": null, "downloads": 0},
+                {"name": "b", "version": "1.10", "release_type": "release",
+                 "release_date": "2025-03-01", "changelog": null, "downloads": 0},
+                {"name": "c", "version": "1.11-beta", "release_type": "beta",
+                 "release_date": "2025-03-01", "changelog": null, "downloads": 0},
+                {"name": "d", "version": "2.0", "release_type": "release",
+                 "release_date": "2025-02-01", "changelog": null, "downloads": 0}
+            ], "links": {"next": null}}"#,
+    )
+    .client(None);
+    let project = client.project("mod").unwrap();
+    assert_eq!(
+        project.resolve(VersionChannel::Latest).unwrap().version,
+        "1.11-beta"
+    );
+    assert_eq!(
+        project.resolve(VersionChannel::Stable).unwrap().version,
+        "1.10"
+    );
+
+    let mut page = project
+        .versions(&ListVersionsParams::default())
+        .unwrap()
+        .data;
+    assert_eq!(versions::latest(&page).unwrap().version, "2.0");
+    versions::sort_newest_first(&mut page);
+    let order: Vec<_> = page.iter().map(|v| v.version.as_str()).collect();
+    assert_eq!(order, ["2.0", "1.11-beta", "1.10", "1.9"]);
 }
 
 // ---------------------------------------------------------------------------
@@ -230,6 +1978,7 @@ fn test_authenticated_operations() {
     };
 
     let client = HubClient::new(&base_url(), Some(&token)).unwrap();
+    let username: Username = username.parse().unwrap();
 
     // 8. Token validation
     println!("[8] Testing token validation");
@@ -287,13 +2036,15 @@ fn test_authenticated_operations() {
                     },
                 ) {
                     if let Some(v) = vers_resp.data.first() {
-                        found_deps.push((proj.slug.clone(), v.version.clone()));
+                        found_deps.push((proj.slug.to_string(), v.version.to_string()));
                         if dependencies_list.is_none() {
                             dependencies_list = Some(vec![hub01_client::Dependency {
-                                project: proj.slug.clone(),
-                                version: v.version.clone(),
-                                dep_type: "optional".into(),
+                                project: proj.slug.to_string(),
+                                version: v.version.to_string(),
+                                dep_type: DependencyKind::Optional,
                                 external: false,
+                                name: None,
+                                url: None,
                             }]);
                             println!("  Using dependency: {} v{}", proj.slug, v.version);
                         }
@@ -314,8 +2065,8 @@ fn test_authenticated_operations() {
         .unwrap_or_default();
     if all_version_tags.len() >= 2 {
         let tags = vec![
-            all_version_tags[0].slug.clone(),
-            all_version_tags[1].slug.clone(),
+            all_version_tags[0].slug.to_string(),
+            all_version_tags[1].slug.to_string(),
         ];
         println!("  Using version tags: {:?}", tags);
         version_tags_list = Some(tags);
@@ -326,7 +2077,7 @@ fn test_authenticated_operations() {
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
         .as_secs();
-    let version_slug = format!("test-api-{}", timestamp);
+    let version_slug: VersionSlug = format!("test-api-{}", timestamp).parse().unwrap();
 
     // Create a dummy file
     let (file_name, file_content) =
@@ -339,14 +2090,14 @@ fn test_authenticated_operations() {
             &test_slug,
             &CreateVersionParams {
                 name: format!("Test Version {version_slug}"),
-                version: version_slug.clone(),
+                version: version_slug.to_string(),
                 release_type: "alpha".into(),
                 release_date: today.clone(),
                 changelog: "Test version created by Rust integration test".into(),
                 tags: version_tags_list,
                 dependencies: dependencies_list,
             },
-            &[(&file_name, file_content)],
+            vec![(&file_name, file_content.into())],
         )
         .unwrap();
     println!("  ✓ Created version: {}", new_version.version);
@@ -361,7 +2112,7 @@ fn test_authenticated_operations() {
 
     // Prepare updated dependencies
     let mut update_dependencies: Option<Vec<hub01_client::Dependency>> = None;
-    if found_deps.len() > 0 {
+    if !found_deps.is_empty() {
         let mut new_deps = Vec::new();
         // Add existing one as required (if we have more than 1)
         for (i, (p, v)) in found_deps.iter().enumerate() {
@@ -369,11 +2120,13 @@ fn test_authenticated_operations() {
                 project: p.clone(),
                 version: v.clone(),
                 dep_type: if i == 0 && found_deps.len() > 1 {
-                    "required".into()
+                    DependencyKind::Required
                 } else {
-                    "optional".into()
+                    DependencyKind::Optional
                 },
                 external: false,
+                name: None,
+                url: None,
             });
             if new_deps.len() >= 2 {
                 break;
@@ -390,9 +2143,9 @@ fn test_authenticated_operations() {
     let mut update_tags_list: Option<Vec<String>> = None;
     if all_version_tags.len() >= 5 {
         let tags = vec![
-            all_version_tags[2].slug.clone(),
-            all_version_tags[3].slug.clone(),
-            all_version_tags[4].slug.clone(),
+            all_version_tags[2].slug.to_string(),
+            all_version_tags[3].slug.to_string(),
+            all_version_tags[4].slug.to_string(),
         ];
         println!("  Updating with version tags: {:?}", tags);
         update_tags_list = Some(tags);
@@ -440,6 +2193,127 @@ fn test_authenticated_operations() {
     }
 }
 
+// ---------------------------------------------------------------------------
+// 14. End-to-end smoke test against a disposable instance
+// ---------------------------------------------------------------------------
+
+/// Full version lifecycle against a throwaway Hub01 instance: create with a
+/// file, find it in the listing, download and verify it, update, delete.
+///
+/// Only runs with `HUB01_SMOKE=1`, since it writes to the server.  Point
+/// `HUB01_BASE_URL` at the instance and provide credentials as for the
+/// authenticated tests; `HUB01_SMOKE_PROJECT` picks the project to use
+/// (default: the user's first project).
+#[test]
+fn test_smoke_lifecycle() {
+    if std::env::var("HUB01_SMOKE").as_deref() != Ok("1") {
+        println!("[14] Skipping smoke test (set HUB01_SMOKE=1)");
+        return;
+    }
+    let (Some(username), Some(token)) = credentials() else {
+        panic!("HUB01_SMOKE=1 needs HUB01_USERNAME and HUB01_TOKEN");
+    };
+    let client = HubClient::new(&base_url(), Some(&token)).unwrap();
+
+    let slug: ProjectSlug = match std::env::var("HUB01_SMOKE_PROJECT") {
+        Ok(slug) => slug.parse().unwrap(),
+        Err(_) => {
+            let username: Username = username.parse().unwrap();
+            let projects = client.users().get_projects(&username).unwrap();
+            projects
+                .data
+                .first()
+                .expect("the smoke-test user needs at least one project")
+                .slug
+                .clone()
+        }
+    };
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis();
+    let version: VersionSlug = format!("smoke-{stamp}").parse().unwrap();
+    let content = format!("hub01 smoke test {stamp}\n").into_bytes();
+    println!("[14] Smoke test on {slug} {version}");
+
+    // Create
+    let created = client
+        .versions()
+        .create(
+            &slug,
+            &CreateVersionParams {
+                name: format!("Smoke {version}"),
+                version: version.to_string(),
+                release_type: "alpha".into(),
+                release_date: chrono_today(),
+                changelog: "Created by the smoke test".into(),
+                tags: None,
+                dependencies: None,
+            },
+            vec![("smoke.txt", content.clone().into())],
+        )
+        .unwrap();
+    assert_eq!(created.version, version);
+    println!("  ✓ Created");
+
+    // The cleanup below must run even if a check fails.
+    let checks = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        // List
+        let listed = client
+            .versions()
+            .list(
+                &slug,
+                &ListVersionsParams {
+                    order_by: VersionOrderBy::ReleaseDate,
+                    order_direction: SortDirection::Desc,
+                    per_page: 50,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(listed.data.iter().any(|v| v.version == version));
+        println!("  ✓ Listed");
+
+        // Download, checked against the SHA-1 the server computed
+        let fetched = client.versions().get(&slug, &version).unwrap();
+        let file = fetched.files.first().expect("uploaded file is listed");
+        assert!(!file.sha1.is_empty(), "server reports no SHA-1");
+        let mut downloaded = Vec::new();
+        client.files().download(file, &mut downloaded).unwrap();
+        assert_eq!(downloaded, content);
+        println!("  ✓ Downloaded and verified ({})", file.sha1);
+
+        // Update
+        let updated = client
+            .versions()
+            .update(
+                &slug,
+                &version,
+                &hub01_client::UpdateVersionParams {
+                    release_type: Some("beta".into()),
+                    changelog: Some("Updated by the smoke test".into()),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        assert_eq!(updated.release_type, "beta");
+        println!("  ✓ Updated");
+    }));
+
+    // Delete
+    client.versions().delete(&slug, &version).unwrap();
+    assert!(matches!(
+        client.versions().get(&slug, &version),
+        Err(HubApiError::NotFound { .. })
+    ));
+    println!("  ✓ Deleted");
+
+    if let Err(panic) = checks {
+        std::panic::resume_unwind(panic);
+    }
+}
+
 /// Simple date helper (avoids pulling in chrono just for tests).
 fn chrono_today() -> String {
     // Use system time to produce YYYY-MM-DD